@@ -0,0 +1,126 @@
+//! Regenerates the vendor registry from IEEE CSV exports.
+//!
+//! Set `EUI_OUI_CSV` to one or more paths (separated like `PATH`) of the
+//! MA-L, MA-M and MA-S files published at <https://regauth.standards.ieee.org/>
+//! to build the `vendors` feature against them instead of the registry that
+//! ships with the crate.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const CSV_VAR: &str = "EUI_OUI_CSV";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={}", CSV_VAR);
+    println!("cargo:rustc-check-cfg=cfg(eui_custom_registry)");
+    println!("cargo:rustc-check-cfg=cfg(eui_core_error)");
+
+    if rustc_minor_version().is_some_and(|minor| minor >= 81) {
+        println!("cargo:rustc-cfg=eui_core_error");
+    }
+
+    let paths = match env::var_os(CSV_VAR) {
+        Some(paths) if env::var_os("CARGO_FEATURE_VENDORS").is_some() => paths,
+        _ => return,
+    };
+
+    // Keyed by the assignment's hex digits, so each table comes out sorted.
+    let mut tables: [BTreeMap<String, String>; 3] = Default::default();
+    for path in env::split_paths(&paths) {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let csv = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        for (line, record) in csv.lines().enumerate().skip(1) {
+            if record.trim().is_empty() {
+                continue;
+            }
+            let fields = split_record(record);
+            let (registry, assignment, organization) = match &fields[..] {
+                [registry, assignment, organization, ..] => (registry, assignment, organization),
+                _ => panic!(
+                    "{}:{}: expected at least 3 fields",
+                    path.display(),
+                    line + 1
+                ),
+            };
+            let table = match (registry.as_str(), assignment.len()) {
+                ("MA-L", 6) => &mut tables[0],
+                ("MA-M", 7) => &mut tables[1],
+                ("MA-S", 9) => &mut tables[2],
+                _ => panic!(
+                    "{}:{}: unexpected assignment {} {}",
+                    path.display(),
+                    line + 1,
+                    registry,
+                    assignment
+                ),
+            };
+            if !assignment.bytes().all(|b| b.is_ascii_hexdigit()) {
+                panic!(
+                    "{}:{}: invalid assignment {}",
+                    path.display(),
+                    line + 1,
+                    assignment
+                );
+            }
+            table.insert(
+                assignment.to_ascii_uppercase(),
+                organization.trim().to_string(),
+            );
+        }
+    }
+
+    let mut out = String::new();
+    for ((name, width), table) in [("REGISTRY", 3), ("REGISTRY_MA_M", 4), ("REGISTRY_MA_S", 5)]
+        .iter()
+        .zip(&tables)
+    {
+        out += &format!("static {}: &[([u8; {}], &str)] = &[\n", name, width);
+        for (assignment, organization) in table {
+            // MA-M and MA-S assignments end on a nibble; pad them to whole octets.
+            let digits = format!("{:0<1$}", assignment, 2 * width);
+            let octets = (0..*width)
+                .map(|i| format!("0x{}", &digits[2 * i..2 * i + 2]))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out += &format!("    ([{}], {:?}),\n", octets, organization);
+        }
+        out += "];\n";
+    }
+
+    let dest = Path::new(&env::var_os("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(dest, out).unwrap();
+    println!("cargo:rustc-cfg=eui_custom_registry");
+}
+
+/// Returns the minor version of the compiler, to enable `core::error::Error`
+/// where it is stable and fall back to `std::error::Error` elsewhere.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    // e.g. "rustc 1.81.0 (eeb90cda1 2024-09-04)"
+    version.split(' ').nth(1)?.split('.').nth(1)?.parse().ok()
+}
+
+/// Splits a CSV record, honoring double-quoted fields.
+fn split_record(record: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = record.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
@@ -0,0 +1,189 @@
+//! Addresses whose width is only known once they are parsed.
+
+use crate::{Error, Oui, ParseOptions, EUI, EUI48, EUI64};
+use core::convert::TryFrom;
+
+/// Either width of address, for input whose width isn't known in advance.
+///
+/// Parsing picks the width from the number of hex digits: 12 for an EUI-48,
+/// 16 for an EUI-64.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EuiAddr {
+    EUI48(EUI48),
+    EUI64(EUI64),
+}
+
+impl EuiAddr {
+    /// Parses `s` under `options`, picking the width like `TryFrom<&str>`.
+    pub fn parse_with(s: &str, options: impl Into<ParseOptions>) -> Result<EuiAddr, Error> {
+        let options = options.into();
+        match EUI48::parse_with(s, options) {
+            Err(Error::InvalidStringLength) => EUI64::parse_with(s, options).map(EuiAddr::EUI64),
+            result => result.map(EuiAddr::EUI48),
+        }
+    }
+
+    pub fn as_eui48(&self) -> Option<EUI48> {
+        match self {
+            EuiAddr::EUI48(eui) => Some(*eui),
+            EuiAddr::EUI64(_) => None,
+        }
+    }
+
+    pub fn as_eui64(&self) -> Option<EUI64> {
+        match self {
+            EuiAddr::EUI48(_) => None,
+            EuiAddr::EUI64(eui) => Some(*eui),
+        }
+    }
+
+    /// Returns the address as an EUI-64, expanding an EUI-48 with FF-FE.
+    pub fn to_eui64(&self) -> EUI64 {
+        match self {
+            EuiAddr::EUI48(eui) => eui.to_eui64(),
+            EuiAddr::EUI64(eui) => *eui,
+        }
+    }
+
+    pub fn is_eui48(&self) -> bool {
+        matches!(self, EuiAddr::EUI48(_))
+    }
+
+    pub fn is_eui64(&self) -> bool {
+        matches!(self, EuiAddr::EUI64(_))
+    }
+
+    pub fn is_multicast(&self) -> bool {
+        self.to_bytes()[0] & 0x01 != 0
+    }
+
+    pub fn is_locally_administered(&self) -> bool {
+        self.to_bytes()[0] & 0x02 != 0
+    }
+
+    pub fn oui(&self) -> Oui {
+        match self {
+            EuiAddr::EUI48(eui) => eui.oui(),
+            EuiAddr::EUI64(eui) => eui.oui(),
+        }
+    }
+}
+
+impl EUI for EuiAddr {
+    fn to_bytes(&self) -> &[u8] {
+        match self {
+            EuiAddr::EUI48(eui) => eui.to_bytes(),
+            EuiAddr::EUI64(eui) => eui.to_bytes(),
+        }
+    }
+}
+
+impl From<EUI48> for EuiAddr {
+    fn from(eui: EUI48) -> Self {
+        EuiAddr::EUI48(eui)
+    }
+}
+
+impl From<EUI64> for EuiAddr {
+    fn from(eui: EUI64) -> Self {
+        EuiAddr::EUI64(eui)
+    }
+}
+
+impl TryFrom<&[u8]> for EuiAddr {
+    type Error = crate::Error;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes.len() {
+            6 => EUI48::try_from(bytes).map(EuiAddr::EUI48),
+            _ => EUI64::try_from(bytes).map(EuiAddr::EUI64),
+        }
+    }
+}
+
+impl TryFrom<&str> for EuiAddr {
+    type Error = crate::Error;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match EUI48::parse_const(s) {
+            Err(Error::InvalidStringLength) => EUI64::parse_const(s).map(EuiAddr::EUI64),
+            result => result.map(EuiAddr::EUI48),
+        }
+    }
+}
+
+impl core::str::FromStr for EuiAddr {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EuiAddr::try_from(s)
+    }
+}
+
+impl core::fmt::Display for EuiAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EuiAddr::EUI48(eui) => eui.fmt(f),
+            EuiAddr::EUI64(eui) => eui.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EuiAddr;
+    use crate::{Error, Eui, Format, ParseOptions, EUI, EUI48, EUI64};
+    use core::convert::TryFrom;
+
+    const EUI48: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+    const EUI64: EUI64 = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+
+    #[test]
+    fn parse_with() {
+        let options = ParseOptions::new().format(Format::Dot);
+        assert_eq!(
+            EuiAddr::parse_with("0A1B.2C3D.4E5F", options),
+            Ok(EuiAddr::EUI48(EUI48))
+        );
+        assert_eq!(
+            EuiAddr::parse_with("00FF.0A1B.2C3D.4E5F", options),
+            Ok(EuiAddr::EUI64(EUI64))
+        );
+        assert_eq!(
+            EuiAddr::parse_with("0A1B-2C3D-4E5F", options),
+            Err(Error::InvalidSeparator { c: '-', index: 4 })
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let addr = EuiAddr::from(EUI48);
+        assert_eq!(addr.as_eui48(), Some(EUI48));
+        assert_eq!(addr.as_eui64(), None);
+        assert!(addr.is_eui48());
+        assert_eq!(addr.to_eui64(), EUI48.to_eui64());
+        assert_eq!(addr.oui(), EUI48.oui());
+        let addr = EuiAddr::from(EUI64);
+        assert_eq!(addr.as_eui64(), Some(EUI64));
+        assert!(addr.is_eui64());
+        assert_eq!(addr.to_eui64(), EUI64);
+        assert!(!addr.is_multicast());
+        assert!(!addr.is_locally_administered());
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(EuiAddr::try_from(&EUI48.octets()[..]), Ok(EUI48.into()));
+        assert_eq!(EuiAddr::try_from(&EUI64.octets()[..]), Ok(EUI64.into()));
+        assert_eq!(
+            EuiAddr::try_from(&[0u8; 7][..]),
+            Err(Error::InvalidSliceLength)
+        );
+        assert_eq!(EuiAddr::from(EUI64).to_bytes(), EUI64.octets());
+    }
+
+    #[test]
+    fn display() {
+        let mut s = String::new();
+        EuiAddr::from(EUI64).write_colon(&mut s).unwrap();
+        assert_eq!(s, "00:FF:0A:1B:2C:3D:4E:5F");
+        assert_eq!(EuiAddr::from(EUI48).to_string(), "0A-1B-2C-3D-4E-5F");
+    }
+}
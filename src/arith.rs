@@ -0,0 +1,105 @@
+//! Arithmetic on addresses as big-endian integers, e.g. for handing out
+//! consecutive addresses from a base.
+
+use crate::{Eui, Width};
+
+impl<const N: usize> Eui<N>
+where
+    [u8; N]: Width,
+{
+    /// The value of the broadcast address; `N` must be at most 8.
+    const MAX_VALUE: u64 = u64::MAX >> (64 - 8 * N);
+
+    /// Builds an address from the low `8 * N` bits of `value`.
+    fn truncate(value: u64) -> Self {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&value.to_be_bytes()[8 - N..]);
+        Eui(bytes)
+    }
+
+    /// Adds `n`, or returns `None` past the broadcast address.
+    pub fn checked_add(&self, n: u64) -> Option<Self> {
+        match self.to_u64().checked_add(n) {
+            Some(value) if value <= Self::MAX_VALUE => Some(Self::truncate(value)),
+            _ => None,
+        }
+    }
+
+    /// Subtracts `n`, or returns `None` below the nil address.
+    pub fn checked_sub(&self, n: u64) -> Option<Self> {
+        self.to_u64().checked_sub(n).map(Self::truncate)
+    }
+
+    /// Adds `n` modulo 2^(8 * N).
+    pub fn wrapping_add(&self, n: u64) -> Self {
+        Self::truncate(self.to_u64().wrapping_add(n))
+    }
+
+    /// Subtracts `n` modulo 2^(8 * N).
+    pub fn wrapping_sub(&self, n: u64) -> Self {
+        Self::truncate(self.to_u64().wrapping_sub(n))
+    }
+
+    /// Returns the next address, or `None` after the broadcast address.
+    pub fn succ(&self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    /// Returns the previous address, or `None` before the nil address.
+    pub fn pred(&self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EUI48, EUI64};
+
+    const EUI: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0xFF]);
+
+    #[test]
+    fn eui48_checked() {
+        assert_eq!(
+            EUI.checked_add(1),
+            Some(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x00]))
+        );
+        assert_eq!(
+            EUI.checked_sub(0x100),
+            Some(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4D, 0xFF]))
+        );
+        assert_eq!(EUI48::BROADCAST.checked_add(1), None);
+        assert_eq!(EUI.checked_add(u64::MAX), None);
+        assert_eq!(EUI48::NIL.checked_sub(1), None);
+    }
+
+    #[test]
+    fn eui48_wrapping() {
+        assert_eq!(EUI48::BROADCAST.wrapping_add(1), EUI48::NIL);
+        assert_eq!(EUI48::NIL.wrapping_sub(1), EUI48::BROADCAST);
+        assert_eq!(EUI.wrapping_add(1 << 48), EUI);
+    }
+
+    #[test]
+    fn eui48_succ_pred() {
+        assert_eq!(EUI.succ().and_then(|eui| eui.pred()), Some(EUI));
+        assert_eq!(EUI48::BROADCAST.succ(), None);
+        assert_eq!(EUI48::NIL.pred(), None);
+    }
+
+    #[test]
+    fn eui64() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0xFF]);
+        assert_eq!(
+            eui.succ(),
+            Some(Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x00]))
+        );
+        assert_eq!(
+            eui.checked_add(2).and_then(|eui| eui.checked_sub(2)),
+            Some(eui)
+        );
+        assert_eq!(EUI64::BROADCAST.succ(), None);
+        assert_eq!(EUI64::BROADCAST.wrapping_add(1), EUI64::NIL);
+        assert_eq!(EUI64::NIL.wrapping_sub(1), EUI64::BROADCAST);
+        assert_eq!(EUI64::NIL.pred(), None);
+    }
+}
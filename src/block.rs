@@ -0,0 +1,224 @@
+//! IEEE Registration Authority assignment blocks: MA-L, MA-M and MA-S.
+
+use crate::{parser, Error, Eui, Eui48Range, EuiPrefix, Oui, EUI48, EUI64};
+use core::convert::TryFrom;
+
+/// Parses the hex digits of an assignment as the IEEE publishes it, e.g.
+/// `70B3D51` for an MA-M, into the base address of the block.
+fn parse_assignment(s: &str, digits: usize) -> Result<EUI48, Error> {
+    let mut value = 0u64;
+    let mut len = 0;
+    for (i, b) in s.bytes().enumerate() {
+        if parser::is_separator(b) {
+            continue;
+        }
+        if !b.is_ascii_hexdigit() {
+            return Err(Error::InvalidHexCharacter {
+                c: parser::char_at(s.as_bytes(), i),
+                index: i,
+            });
+        }
+        value = value << 4 | u64::from(parser::hex_value(b));
+        len += 1;
+        if len > digits {
+            return Err(Error::InvalidStringLength);
+        }
+    }
+    if len != digits {
+        return Err(Error::InvalidStringLength);
+    }
+    EUI48::from_u64(value << (48 - 4 * digits))
+}
+
+macro_rules! block {
+    ($(#[$doc:meta])* $name:ident, $prefix_len:literal) => {
+        $(#[$doc])*
+        ///
+        /// `FromStr` accepts the assignment's hex digits, as in the IEEE
+        /// registry, or a prefix like `AC-DE-48-00-00-00/24`, which is also
+        /// how `Display` writes it.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(EuiPrefix);
+
+        impl $name {
+            pub const PREFIX_LEN: u8 = $prefix_len;
+
+            /// Builds the block that `base` belongs to.
+            pub fn new(base: EUI48) -> $name {
+                // The length is in range.
+                $name(EuiPrefix::new(base, $prefix_len).unwrap())
+            }
+
+            /// Wraps `prefix`, failing with `InvalidPrefix` if its length is
+            /// not [`PREFIX_LEN`](Self::PREFIX_LEN).
+            pub fn from_prefix(prefix: EuiPrefix) -> Result<$name, Error> {
+                if prefix.prefix_len() != $prefix_len {
+                    return Err(Error::InvalidPrefix);
+                }
+                Ok($name(prefix))
+            }
+
+            pub fn prefix(&self) -> EuiPrefix {
+                self.0
+            }
+
+            pub fn base(&self) -> EUI48 {
+                self.0.base()
+            }
+
+            pub fn matches(&self, eui: &EUI48) -> bool {
+                self.0.matches(eui)
+            }
+
+            /// Returns the number of EUI-48s in the block.
+            pub const fn eui48_count(&self) -> u64 {
+                1 << (48 - $prefix_len)
+            }
+
+            /// Returns the number of EUI-64s in the block.
+            pub const fn eui64_count(&self) -> u64 {
+                1 << (64 - $prefix_len)
+            }
+
+            /// Returns the `n`th EUI-48 of the block, counting from zero.
+            pub fn nth_eui48(&self, n: u64) -> Option<EUI48> {
+                if n >= self.eui48_count() {
+                    return None;
+                }
+                self.base().checked_add(n)
+            }
+
+            /// Returns the `n`th EUI-64 of the block, counting from zero.
+            pub fn nth_eui64(&self, n: u64) -> Option<EUI64> {
+                if n >= self.eui64_count() {
+                    return None;
+                }
+                EUI64::from_u64(self.base().to_u64() << 16).checked_add(n)
+            }
+
+            /// Returns every EUI-48 of the block.
+            pub fn range(&self) -> Eui48Range {
+                self.0.range()
+            }
+        }
+
+        impl From<$name> for EuiPrefix {
+            fn from(block: $name) -> Self {
+                block.0
+            }
+        }
+
+        impl TryFrom<EuiPrefix> for $name {
+            type Error = crate::Error;
+            fn try_from(prefix: EuiPrefix) -> Result<Self, Self::Error> {
+                $name::from_prefix(prefix)
+            }
+        }
+
+        impl core::str::FromStr for $name {
+            type Err = crate::Error;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.contains('/') {
+                    return $name::from_prefix(s.parse()?);
+                }
+                parse_assignment(s, $prefix_len / 4).map($name::new)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+block!(
+    /// An MA-L (large) assignment: a 24-bit OUI.
+    MaL,
+    24
+);
+
+block!(
+    /// An MA-M (medium) assignment: a 28-bit prefix.
+    MaM,
+    28
+);
+
+block!(
+    /// An MA-S (small) assignment: a 36-bit prefix.
+    MaS,
+    36
+);
+
+impl MaL {
+    pub fn oui(&self) -> Oui {
+        self.base().oui()
+    }
+}
+
+impl From<Oui> for MaL {
+    fn from(oui: Oui) -> Self {
+        let o = oui.octets();
+        MaL::new(Eui([o[0], o[1], o[2], 0, 0, 0]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MaL, MaM, MaS};
+    use crate::{Error, Eui, Oui, EUI48};
+
+    #[test]
+    fn parse() {
+        let ma_m: MaM = "70B3D51".parse().unwrap();
+        assert_eq!(ma_m.base(), Eui([0x70, 0xB3, 0xD5, 0x10, 0x00, 0x00]));
+        assert_eq!(ma_m.to_string(), "70-B3-D5-10-00-00/28");
+        assert_eq!(ma_m.to_string().parse(), Ok(ma_m));
+        let ma_s: MaS = "70-B3-D5-10-0".parse().unwrap();
+        assert_eq!(ma_s.base(), Eui([0x70, 0xB3, 0xD5, 0x10, 0x00, 0x00]));
+        assert_eq!(
+            "70-B3-D5-10-00-00/24".parse::<MaM>(),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!("70B3D5".parse::<MaM>(), Err(Error::InvalidStringLength));
+        assert_eq!("70B3D512".parse::<MaM>(), Err(Error::InvalidStringLength));
+        assert_eq!(
+            "70B3G51".parse::<MaM>(),
+            Err(Error::InvalidHexCharacter { c: 'G', index: 4 })
+        );
+    }
+
+    #[test]
+    fn counts() {
+        let ma_l = MaL::from(Oui::new([0xAC, 0xDE, 0x48]));
+        assert_eq!(ma_l.eui48_count(), 1 << 24);
+        assert_eq!(ma_l.eui64_count(), 1 << 40);
+        let ma_s = MaS::new(EUI48::NIL);
+        assert_eq!(ma_s.eui48_count(), 4096);
+        assert_eq!(ma_s.range().len(), 4096);
+    }
+
+    #[test]
+    fn nth() {
+        let ma_m: MaM = "70B3D51".parse().unwrap();
+        assert_eq!(
+            ma_m.nth_eui48(0x1_2345),
+            Some(Eui([0x70, 0xB3, 0xD5, 0x11, 0x23, 0x45]))
+        );
+        assert_eq!(ma_m.nth_eui48(ma_m.eui48_count()), None);
+        assert_eq!(
+            ma_m.nth_eui64(0xF_FFFF_FFFF),
+            Some(Eui([0x70, 0xB3, 0xD5, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF]))
+        );
+        assert_eq!(ma_m.nth_eui64(ma_m.eui64_count()), None);
+        assert!(ma_m.matches(&ma_m.nth_eui48(5).unwrap()));
+    }
+
+    #[test]
+    fn ma_l_oui() {
+        let ma_l = MaL::new(Eui([0xAC, 0xDE, 0x48, 0x12, 0x34, 0x56]));
+        assert_eq!(ma_l.oui(), Oui::new([0xAC, 0xDE, 0x48]));
+        assert_eq!(ma_l.to_string(), "AC-DE-48-00-00-00/24");
+    }
+}
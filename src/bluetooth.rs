@@ -0,0 +1,114 @@
+//! Bluetooth device addresses.
+
+use crate::{Error, EUI, EUI48};
+
+/// A Bluetooth device address (BD_ADDR).
+///
+/// Addresses are shown most significant byte first, like other EUI-48s, but
+/// HCI and the air interface carry them least significant byte first. Use
+/// [`from_hci_bytes`](BdAddr::from_hci_bytes) and
+/// [`to_hci_bytes`](BdAddr::to_hci_bytes) at that boundary.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BdAddr(EUI48);
+
+impl BdAddr {
+    pub const fn new(eui: EUI48) -> BdAddr {
+        BdAddr(eui)
+    }
+
+    pub const fn eui48(&self) -> EUI48 {
+        self.0
+    }
+
+    /// Builds an address from the Non-significant Address Part (16 bits),
+    /// the Upper Address Part (8 bits) and the Lower Address Part (24 bits).
+    pub fn from_parts(nap: u16, uap: u8, lap: u32) -> Result<BdAddr, Error> {
+        let [n0, n1] = nap.to_be_bytes();
+        EUI48::from_oui_and_u24([n0, n1, uap], lap).map(BdAddr)
+    }
+
+    /// Reads an address in HCI order, least significant byte first.
+    pub const fn from_hci_bytes(bytes: [u8; 6]) -> BdAddr {
+        BdAddr(EUI48::from_le_bytes(bytes))
+    }
+
+    /// Returns the address in HCI order, least significant byte first.
+    pub const fn to_hci_bytes(&self) -> [u8; 6] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the Non-significant Address Part, the top 16 bits.
+    pub const fn nap(&self) -> u16 {
+        u16::from_be_bytes([self.0 .0[0], self.0 .0[1]])
+    }
+
+    /// Returns the Upper Address Part, the 8 bits after the NAP.
+    pub const fn uap(&self) -> u8 {
+        self.0 .0[2]
+    }
+
+    /// Returns the Lower Address Part, the low 24 bits.
+    pub const fn lap(&self) -> u32 {
+        u32::from_be_bytes([0, self.0 .0[3], self.0 .0[4], self.0 .0[5]])
+    }
+}
+
+impl From<EUI48> for BdAddr {
+    fn from(eui: EUI48) -> Self {
+        BdAddr(eui)
+    }
+}
+
+impl From<BdAddr> for EUI48 {
+    fn from(addr: BdAddr) -> Self {
+        addr.0
+    }
+}
+
+impl core::str::FromStr for BdAddr {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EUI48::parse_const(s).map(BdAddr)
+    }
+}
+
+/// Formats the address with colons, as Bluetooth stacks show it.
+impl core::fmt::Display for BdAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.write_colon(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BdAddr;
+    use crate::{Error, Eui, EUI48};
+
+    const ADDR: BdAddr = BdAddr(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+
+    #[test]
+    fn hci_bytes() {
+        let hci = [0x5F, 0x4E, 0x3D, 0x2C, 0x1B, 0x0A];
+        assert_eq!(BdAddr::from_hci_bytes(hci), ADDR);
+        assert_eq!(ADDR.to_hci_bytes(), hci);
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(ADDR.nap(), 0x0A1B);
+        assert_eq!(ADDR.uap(), 0x2C);
+        assert_eq!(ADDR.lap(), 0x3D_4E5F);
+        assert_eq!(BdAddr::from_parts(0x0A1B, 0x2C, 0x3D_4E5F), Ok(ADDR));
+        assert_eq!(
+            BdAddr::from_parts(0x0A1B, 0x2C, 0x100_0000),
+            Err(Error::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ADDR.to_string(), "0A:1B:2C:3D:4E:5F");
+        assert_eq!("0a:1b:2c:3d:4e:5f".parse(), Ok(ADDR));
+        assert_eq!(EUI48::from(ADDR), ADDR.eui48());
+    }
+}
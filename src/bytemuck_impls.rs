@@ -0,0 +1,42 @@
+//! Marks addresses as plain bytes for `bytemuck`, so they can be cast from
+//! and to packet buffers without copying.
+
+use crate::{Eui, Width};
+
+// SAFETY: `Eui<N>` is `repr(transparent)` over `[u8; N]`, which has no
+// padding and for which every bit pattern, zero included, is valid.
+unsafe impl<const N: usize> bytemuck::Zeroable for Eui<N> where [u8; N]: Width {}
+
+// SAFETY: as above; `Eui<N>` is also `Copy` and `'static`.
+unsafe impl<const N: usize> bytemuck::Pod for Eui<N> where [u8; N]: Width {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EUI48, EUI64};
+
+    const FRAME: [u8; 14] = [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F, 0x08, 0x06,
+    ];
+
+    #[test]
+    fn cast_from_bytes() {
+        let dst: &EUI48 = bytemuck::from_bytes(&FRAME[..6]);
+        assert!(dst.is_broadcast());
+        let addresses: &[EUI48] = bytemuck::cast_slice(&FRAME[..12]);
+        assert_eq!(addresses[1], Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert!(bytemuck::try_from_bytes::<EUI64>(&FRAME[..6]).is_err());
+    }
+
+    #[test]
+    fn cast_to_bytes() {
+        let mut frame = FRAME;
+        let src: &mut EUI48 = bytemuck::from_bytes_mut(&mut frame[6..12]);
+        src.set_multicast_bit(true);
+        assert_eq!(frame[6], 0x0B);
+
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(bytemuck::bytes_of(&eui), &FRAME[6..12]);
+        assert_eq!(bytemuck::cast::<EUI48, [u8; 6]>(eui), eui.octets());
+        assert_eq!(<EUI48 as bytemuck::Zeroable>::zeroed(), EUI48::NIL);
+    }
+}
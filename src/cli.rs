@@ -0,0 +1,53 @@
+//! `clap` integration, so addresses can be used directly as argument types.
+
+use crate::{Error, EUI48, EUI64};
+use clap::builder::ValueParserFactory;
+use std::convert::TryFrom;
+
+pub fn value_parser48(s: &str) -> Result<EUI48, Error> {
+    EUI48::try_from(s)
+}
+
+pub fn value_parser64(s: &str) -> Result<EUI64, Error> {
+    EUI64::try_from(s)
+}
+
+impl ValueParserFactory for EUI48 {
+    type Parser = fn(&str) -> Result<EUI48, Error>;
+    fn value_parser() -> Self::Parser {
+        value_parser48
+    }
+}
+
+impl ValueParserFactory for EUI64 {
+    type Parser = fn(&str) -> Result<EUI64, Error>;
+    fn value_parser() -> Self::Parser {
+        value_parser64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EUI48, EUI64};
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Args {
+        #[arg(long)]
+        mac: EUI48,
+        #[arg(long)]
+        eui64: Option<EUI64>,
+    }
+
+    #[test]
+    fn parse_mac_argument() {
+        let args = Args::try_parse_from(["prog", "--mac", "0A:1B:2C:3D:4E:5F"]).unwrap();
+        assert_eq!(args.mac, Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(args.eui64, None);
+    }
+
+    #[test]
+    fn parse_invalid_mac_argument() {
+        assert!(Args::try_parse_from(["prog", "--mac", "0A:1B:2C:3D:4E"]).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+//! Logs addresses through `defmt`. Each octet goes over the wire as a raw
+//! byte; the host formats them.
+
+use crate::{EuiAddr, EUI48, EUI64};
+use defmt::{Format, Formatter};
+
+impl Format for EUI48 {
+    fn format(&self, f: Formatter<'_>) {
+        let b = self.0;
+        defmt::write!(
+            f,
+            "{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}",
+            b[0],
+            b[1],
+            b[2],
+            b[3],
+            b[4],
+            b[5]
+        )
+    }
+}
+
+impl Format for EUI64 {
+    fn format(&self, f: Formatter<'_>) {
+        let b = self.0;
+        defmt::write!(
+            f,
+            "{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}",
+            b[0],
+            b[1],
+            b[2],
+            b[3],
+            b[4],
+            b[5],
+            b[6],
+            b[7]
+        )
+    }
+}
+
+impl Format for EuiAddr {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            EuiAddr::EUI48(eui) => eui.format(f),
+            EuiAddr::EUI64(eui) => eui.format(f),
+        }
+    }
+}
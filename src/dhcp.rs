@@ -0,0 +1,200 @@
+//! DHCP client identifiers and DHCPv6 DUIDs built from an EUI-48.
+
+use crate::format::write_grouped;
+use crate::{parser, Case, Error, Format, EUI48};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::convert::TryFrom;
+
+/// The ARP hardware type of Ethernet, which prefixes the address in client
+/// identifiers and DUIDs.
+const HTYPE_ETHERNET: u8 = 1;
+
+const DUID_LLT: u16 = 1;
+const DUID_LL: u16 = 3;
+
+impl EUI48 {
+    /// Returns the DHCPv4 client identifier (option 61) of the address: the
+    /// Ethernet hardware type followed by the address.
+    pub const fn to_client_id(&self) -> [u8; 7] {
+        let o = self.0;
+        [HTYPE_ETHERNET, o[0], o[1], o[2], o[3], o[4], o[5]]
+    }
+
+    /// Reads an Ethernet client identifier.
+    pub fn from_client_id(bytes: &[u8]) -> Result<EUI48, Error> {
+        match bytes {
+            [HTYPE_ETHERNET, address @ ..] => EUI48::try_from(address),
+            [_, ..] if bytes.len() == 7 => Err(Error::InvalidPrefix),
+            _ => Err(Error::InvalidSliceLength),
+        }
+    }
+
+    /// Writes the client identifier as in DHCP server configuration, e.g.
+    /// `01:0A:1B:2C:3D:4E:5F`.
+    pub fn write_client_id(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        write_grouped(&self.to_client_id(), w, Format::Colon, Case::Upper)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_client_id_fmt(&self) -> String {
+        crate::to_string_with(|s| self.write_client_id(s))
+    }
+
+    /// Parses a client identifier written with any of the usual separators.
+    pub fn parse_client_id(s: &str) -> Result<EUI48, Error> {
+        let bytes: [u8; 7] = parser::decode(s, true)?;
+        EUI48::from_client_id(&bytes)
+    }
+
+    /// Returns the DUID-LL (type 3) of the address.
+    pub const fn to_duid_ll(&self) -> [u8; 10] {
+        let [t0, t1] = DUID_LL.to_be_bytes();
+        let o = self.0;
+        [
+            t0,
+            t1,
+            0,
+            HTYPE_ETHERNET,
+            o[0],
+            o[1],
+            o[2],
+            o[3],
+            o[4],
+            o[5],
+        ]
+    }
+
+    /// Returns the DUID-LLT (type 1) of the address, with `time` in seconds
+    /// since midnight UTC, January 1, 2000.
+    pub const fn to_duid_llt(&self, time: u32) -> [u8; 14] {
+        let [t0, t1] = DUID_LLT.to_be_bytes();
+        let [s0, s1, s2, s3] = time.to_be_bytes();
+        let o = self.0;
+        [
+            t0,
+            t1,
+            0,
+            HTYPE_ETHERNET,
+            s0,
+            s1,
+            s2,
+            s3,
+            o[0],
+            o[1],
+            o[2],
+            o[3],
+            o[4],
+            o[5],
+        ]
+    }
+
+    /// Reads the address out of an Ethernet DUID-LL or DUID-LLT.
+    pub fn from_duid(bytes: &[u8]) -> Result<EUI48, Error> {
+        let (duid_type, rest) = match bytes {
+            [t0, t1, 0, HTYPE_ETHERNET, rest @ ..] => (u16::from_be_bytes([*t0, *t1]), rest),
+            [_, _, _, _, ..] => return Err(Error::InvalidPrefix),
+            _ => return Err(Error::InvalidSliceLength),
+        };
+        match (duid_type, rest.len()) {
+            (DUID_LL, 6) => EUI48::try_from(rest),
+            (DUID_LLT, 10) => EUI48::try_from(&rest[4..]),
+            (DUID_LL, _) | (DUID_LLT, _) => Err(Error::InvalidSliceLength),
+            _ => Err(Error::InvalidPrefix),
+        }
+    }
+
+    /// Parses a DUID-LL or DUID-LLT written as hex, e.g.
+    /// `00:03:00:01:0a:1b:2c:3d:4e:5f`.
+    pub fn parse_duid(s: &str) -> Result<EUI48, Error> {
+        match parser::decode::<10>(s, true) {
+            Ok(bytes) => EUI48::from_duid(&bytes),
+            Err(Error::InvalidStringLength) => EUI48::from_duid(&parser::decode::<14>(s, true)?),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Eui, EUI48};
+
+    const EUI: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+
+    #[test]
+    fn client_id() {
+        let id = [0x01, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F];
+        assert_eq!(EUI.to_client_id(), id);
+        assert_eq!(EUI48::from_client_id(&id), Ok(EUI));
+        assert_eq!(
+            EUI48::from_client_id(&[0x06, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            EUI48::from_client_id(&id[..6]),
+            Err(Error::InvalidSliceLength)
+        );
+    }
+
+    #[test]
+    fn client_id_text() {
+        let mut s = String::new();
+        EUI.write_client_id(&mut s).unwrap();
+        assert_eq!(s, "01:0A:1B:2C:3D:4E:5F");
+        assert_eq!(EUI48::parse_client_id(&s), Ok(EUI));
+        assert_eq!(EUI48::parse_client_id("010a1b2c3d4e5f"), Ok(EUI));
+        assert_eq!(
+            EUI48::parse_client_id("0A:1B:2C:3D:4E:5F"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn duid_ll() {
+        let duid = EUI.to_duid_ll();
+        assert_eq!(duid, [0, 3, 0, 1, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(EUI48::from_duid(&duid), Ok(EUI));
+        assert_eq!(EUI48::parse_duid("00:03:00:01:0a:1b:2c:3d:4e:5f"), Ok(EUI));
+    }
+
+    #[test]
+    fn duid_llt() {
+        let duid = EUI.to_duid_llt(0x2A2B_2C2D);
+        assert_eq!(
+            duid,
+            [0, 1, 0, 1, 0x2A, 0x2B, 0x2C, 0x2D, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]
+        );
+        assert_eq!(EUI48::from_duid(&duid), Ok(EUI));
+        assert_eq!(
+            EUI48::parse_duid("00:01:00:01:2a:2b:2c:2d:0a:1b:2c:3d:4e:5f"),
+            Ok(EUI)
+        );
+    }
+
+    #[test]
+    fn duid_invalid() {
+        // DUID-EN, and DUID-LL of a non-Ethernet link.
+        assert_eq!(
+            EUI48::from_duid(&[0, 2, 0, 1, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            EUI48::from_duid(&[0, 3, 0, 6, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            EUI48::from_duid(&[0, 3, 0, 1, 0x0A, 0x1B]),
+            Err(Error::InvalidSliceLength)
+        );
+        assert_eq!(
+            EUI48::parse_duid("00:03:00:01:0a:1b"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_client_id_fmt() {
+        assert_eq!(EUI.to_client_id_fmt(), "01:0A:1B:2C:3D:4E:5F");
+    }
+}
@@ -0,0 +1,53 @@
+//! Addresses of Ethernet frames.
+
+use crate::{Error, Eui, EUI48};
+
+/// The destination and source addresses and the EtherType or length field.
+const HEADER_LEN: usize = 14;
+
+impl EUI48 {
+    /// Returns the destination and source addresses of an Ethernet II or
+    /// IEEE 802.3 frame, starting at the destination address, failing with
+    /// `InvalidSliceLength` if it is shorter than a header.
+    pub fn from_ethernet_frame(frame: &[u8]) -> Result<(EUI48, EUI48), Error> {
+        if frame.len() < HEADER_LEN {
+            return Err(Error::InvalidSliceLength);
+        }
+        let mut dst = [0u8; 6];
+        let mut src = [0u8; 6];
+        dst.copy_from_slice(&frame[..6]);
+        src.copy_from_slice(&frame[6..12]);
+        Ok((Eui(dst), Eui(src)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Eui, EUI48};
+
+    #[test]
+    fn from_ethernet_frame() {
+        // An ARP request, padding omitted.
+        let frame = [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F, 0x08, 0x06,
+            0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x01,
+        ];
+        let (dst, src) = EUI48::from_ethernet_frame(&frame).unwrap();
+        assert_eq!(dst, EUI48::BROADCAST);
+        assert_eq!(src, Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(EUI48::from_ethernet_frame(&frame[..14]), Ok((dst, src)));
+    }
+
+    #[test]
+    fn from_ethernet_frame_short() {
+        let frame = [0xFF; 13];
+        assert_eq!(
+            EUI48::from_ethernet_frame(&frame),
+            Err(Error::InvalidSliceLength)
+        );
+        assert_eq!(
+            EUI48::from_ethernet_frame(&[]),
+            Err(Error::InvalidSliceLength)
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! The textual layouts an address can be written in.
+
+use crate::{parser, Error, Eui, Width};
+
+/// A textual layout, e.g. the separator style received from a device, so
+/// that it can be reproduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// `0A-1B-2C-3D-4E-5F`, as in IEEE 802.
+    Canonical,
+    /// `0A:1B:2C:3D:4E:5F`, as used by most Unix tools.
+    Colon,
+    /// `0A 1B 2C 3D 4E 5F`.
+    Spaced,
+    /// `0A1B.2C3D.4E5F`, as used by Cisco.
+    Dot,
+    /// `0A1B-2C3D-4E5F`, as used (in lowercase) by HP ProCurve and some SAN
+    /// gear.
+    Hp,
+    /// `0A1B2C3D4E5F`.
+    Bare,
+}
+
+/// The letter case of the hex digits A to F.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Case {
+    #[default]
+    Upper,
+    Lower,
+}
+
+impl Format {
+    const ALL: [Format; 6] = [
+        Format::Canonical,
+        Format::Colon,
+        Format::Spaced,
+        Format::Dot,
+        Format::Hp,
+        Format::Bare,
+    ];
+
+    /// The separator and the number of octets between separators.
+    fn grouping(self) -> Option<(u8, usize)> {
+        match self {
+            Format::Canonical => Some((b'-', 1)),
+            Format::Colon => Some((b':', 1)),
+            Format::Spaced => Some((b' ', 1)),
+            Format::Dot => Some((b'.', 2)),
+            Format::Hp => Some((b'-', 2)),
+            Format::Bare => None,
+        }
+    }
+
+    /// Returns the length of an `octets`-long address in this layout.
+    fn width(self, octets: usize) -> usize {
+        match self.grouping() {
+            Some((_, group)) => 2 * octets + octets / group - 1,
+            None => 2 * octets,
+        }
+    }
+
+    fn is_separator_at(self, i: usize) -> bool {
+        match self.grouping() {
+            Some((_, group)) => i % (2 * group + 1) == 2 * group,
+            None => false,
+        }
+    }
+
+    /// Checks that `s` is laid out like an `octets`-long address in this
+    /// format.
+    pub(crate) fn check(self, s: &str, octets: usize) -> Result<(), Error> {
+        if s.len() != self.width(octets) {
+            return Err(Error::InvalidStringLength);
+        }
+        for (i, b) in s.bytes().enumerate() {
+            let c = || parser::char_at(s.as_bytes(), i);
+            match self.grouping() {
+                Some((separator, _)) if self.is_separator_at(i) => {
+                    if b != separator {
+                        return Err(Error::InvalidSeparator { c: c(), index: i });
+                    }
+                }
+                _ => {
+                    if !b.is_ascii_hexdigit() {
+                        return Err(Error::InvalidHexCharacter { c: c(), index: i });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes `s`, which must be laid out exactly as `format` prescribes.
+pub(crate) fn parse_with<const N: usize>(s: &str, format: Format) -> Result<[u8; N], Error> {
+    format.check(s, N)?;
+    parser::decode(s, format != Format::Bare)
+}
+
+/// Decodes `s` if it is laid out exactly as one of the [`Format`]s, rather
+/// than with separators anywhere.
+pub(crate) fn parse_strict<const N: usize>(s: &str) -> Result<[u8; N], Error> {
+    // Of the formats whose length matched, report the one that got furthest.
+    let position = |e: &Error| match e {
+        Error::InvalidHexCharacter { index, .. } | Error::InvalidSeparator { index, .. } => {
+            Some(*index)
+        }
+        _ => None,
+    };
+    let mut error = Error::InvalidStringLength;
+    for format in Format::ALL.iter().copied() {
+        match parse_with(s, format) {
+            Err(e) if position(&e) > position(&error) => error = e,
+            Err(_) => {}
+            ok => return ok,
+        }
+    }
+    Err(error)
+}
+
+/// Writes `bytes` as hex laid out as `format`.
+pub(crate) fn write_grouped(
+    bytes: &[u8],
+    w: &mut impl core::fmt::Write,
+    format: Format,
+    case: Case,
+) -> core::fmt::Result {
+    for (i, b) in bytes.iter().enumerate() {
+        if let Some((separator, group)) = format.grouping() {
+            if i > 0 && i % group == 0 {
+                w.write_char(char::from(separator))?;
+            }
+        }
+        match case {
+            Case::Upper => write!(w, "{:02X}", b)?,
+            Case::Lower => write!(w, "{:02x}", b)?,
+        }
+    }
+    Ok(())
+}
+
+/// Returns the layout of `s` if it is an EUI-48 or EUI-64 in one of the
+/// [`Format`]s.
+pub fn detect_format(s: &str) -> Option<Format> {
+    Format::ALL
+        .iter()
+        .copied()
+        .find(|format| format.check(s, 6).is_ok() || format.check(s, 8).is_ok())
+}
+
+/// Writes `bytes` as digits of `bits` bits each into `buf`, which holds an
+/// address of up to 8 octets in binary.
+fn digits<'a>(bytes: &[u8], bits: usize, case: Case, buf: &'a mut [u8; 64]) -> &'a str {
+    let table = match case {
+        Case::Upper => b"0123456789ABCDEF",
+        Case::Lower => b"0123456789abcdef",
+    };
+    let per_byte = 8 / bits;
+    for (i, b) in bytes.iter().enumerate() {
+        for j in 0..per_byte {
+            let shift = 8 - bits * (j + 1);
+            buf[i * per_byte + j] = table[usize::from(b >> shift) & ((1 << bits) - 1)];
+        }
+    }
+    // Only ever filled with ASCII digits.
+    core::str::from_utf8(&buf[..bytes.len() * per_byte]).unwrap_or_default()
+}
+
+/// Writes the address as bare hex digits, with a `0x` prefix under `{:#X}`,
+/// honoring width and fill like the integer types do.
+impl<const N: usize> core::fmt::UpperHex for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", digits(&self.0, 4, Case::Upper, &mut [0; 64]))
+    }
+}
+
+/// Writes the address as bare hex digits, with a `0x` prefix under `{:#x}`,
+/// honoring width and fill like the integer types do.
+impl<const N: usize> core::fmt::LowerHex for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", digits(&self.0, 4, Case::Lower, &mut [0; 64]))
+    }
+}
+
+/// Writes all `8 * N` bits of the address, with a `0b` prefix under `{:#b}`.
+impl<const N: usize> core::fmt::Binary for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0b", digits(&self.0, 1, Case::Lower, &mut [0; 64]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_format, Case, Format};
+    use crate::{Error, Eui, EUI, EUI48, EUI64};
+
+    #[test]
+    fn detect() {
+        assert_eq!(detect_format("0A-1B-2C-3D-4E-5F"), Some(Format::Canonical));
+        assert_eq!(detect_format("0a:1b:2c:3d:4e:5f"), Some(Format::Colon));
+        assert_eq!(detect_format("0A 1B 2C 3D 4E 5F"), Some(Format::Spaced));
+        assert_eq!(detect_format("0A1B.2C3D.4E5F"), Some(Format::Dot));
+        assert_eq!(detect_format("0a1b-2c3d-4e5f"), Some(Format::Hp));
+        assert_eq!(detect_format("0A1B2C3D4E5F"), Some(Format::Bare));
+        assert_eq!(detect_format("00FF.0A1B.2C3D.4E5F"), Some(Format::Dot));
+        assert_eq!(
+            detect_format("00:FF:0A:1B:2C:3D:4E:5F"),
+            Some(Format::Colon)
+        );
+    }
+
+    #[test]
+    fn detect_none() {
+        assert_eq!(detect_format("0A-1B:2C-3D-4E-5F"), None);
+        assert_eq!(detect_format("0A-1B-2C-3D-4E"), None);
+        assert_eq!(detect_format("0A1B2C3D4E5G"), None);
+        assert_eq!(detect_format(""), None);
+    }
+
+    #[test]
+    fn parse_with() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(EUI48::parse_with("0A1B.2C3D.4E5F", Format::Dot), Ok(eui));
+        assert_eq!(EUI48::parse_with("0a1b2c3d4e5f", Format::Bare), Ok(eui));
+        assert_eq!(
+            EUI48::parse_with("0A:1B:2C:3D:4E:5F", Format::Canonical),
+            Err(Error::InvalidSeparator { c: ':', index: 2 })
+        );
+        assert_eq!(
+            EUI64::parse_with("0A1B.2C3D.4E5F", Format::Dot),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn write_format_round_trip() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        for format in Format::ALL.iter().copied() {
+            let mut s = String::new();
+            eui.write_format(&mut s, format).unwrap();
+            assert_eq!(detect_format(&s), Some(format));
+            assert_eq!(EUI64::parse_with(&s, format), Ok(eui));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_fmt(Format::Bare), "0A1B2C3D4E5F");
+        assert_eq!(eui.to_fmt(Format::Dot), eui.to_dot_fmt());
+    }
+
+    #[test]
+    fn write_lowercase() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let mut s = String::new();
+        eui.write_format_with(&mut s, Format::Colon, Case::Lower)
+            .unwrap();
+        assert_eq!(s, "0a:1b:2c:3d:4e:5f");
+        assert_eq!(detect_format(&s), Some(Format::Colon));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_fmt_with() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.to_fmt_with(Format::Dot, Case::Lower),
+            "00ff.0a1b.2c3d.4e5f"
+        );
+        assert_eq!(eui.to_fmt_with(Format::Dot, Case::Upper), eui.to_dot_fmt());
+    }
+
+    #[test]
+    fn parse_strict() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(EUI48::parse_strict("0A-1B-2C-3D-4E-5F"), Ok(eui));
+        assert_eq!(EUI48::parse_strict("0a:1b:2c:3d:4e:5f"), Ok(eui));
+        assert_eq!(EUI48::parse_strict("0A1B.2C3D.4E5F"), Ok(eui));
+        assert_eq!(EUI48::parse_strict("0A1B2C3D4E5F"), Ok(eui));
+        assert_eq!(
+            EUI64::parse_strict("00FF.0A1B.2C3D.4E5F"),
+            Ok(Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+    }
+
+    #[test]
+    fn parse_strict_rejects_misplaced_separators() {
+        assert_eq!(
+            EUI48::parse_strict("0A:1B-2C:3D:4E:5F"),
+            Err(Error::InvalidSeparator { c: '-', index: 5 })
+        );
+        assert_eq!(
+            EUI48::parse_strict("0A:1B-2C.3D4E5F"),
+            Err(Error::InvalidStringLength)
+        );
+        assert_eq!(
+            EUI48::parse_strict("0A1B2.C3D4.E5F"),
+            Err(Error::InvalidSeparator { c: '2', index: 4 })
+        );
+        assert_eq!(
+            EUI48::parse_strict("00-FF-0A-1B-2C-3D-4E-5F"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn hp() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let mut s = String::new();
+        eui.write_format_with(&mut s, Format::Hp, Case::Lower)
+            .unwrap();
+        assert_eq!(s, "0a1b-2c3d-4e5f");
+        assert_eq!(EUI48::parse_with(&s, Format::Hp), Ok(eui));
+        assert_eq!(
+            EUI48::parse_with("0a1b-2c3d.4e5f", Format::Hp),
+            Err(Error::InvalidSeparator { c: '.', index: 9 })
+        );
+    }
+
+    #[test]
+    fn hex_traits() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(format!("{:X}", eui), "0A1B2C3D4E5F");
+        assert_eq!(format!("{:x}", eui), "0a1b2c3d4e5f");
+        assert_eq!(format!("{:#x}", eui), "0x0a1b2c3d4e5f");
+        assert_eq!(format!("{:>14X}", eui), "  0A1B2C3D4E5F");
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(format!("{:#X}", eui), "0x00FF0A1B2C3D4E5F");
+    }
+
+    #[test]
+    fn binary() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            format!("{:b}", eui),
+            "000010100001101100101100001111010100111001011111"
+        );
+        let eui = EUI64::BROADCAST;
+        assert_eq!(format!("{:#b}", eui), format!("{:#b}", u64::MAX));
+    }
+}
@@ -0,0 +1,45 @@
+//! Formatting into `heapless::String`s, for owned strings without `alloc`.
+
+use crate::{Case, Eui, Format, Width, EUI};
+
+impl<const N: usize> Eui<N>
+where
+    [u8; N]: Width,
+{
+    /// Formats the address as `format` into a string of capacity `CAP`, which
+    /// is checked at compile time to fit the canonical form, the widest one.
+    pub fn to_heapless<const CAP: usize>(
+        &self,
+        format: Format,
+        case: Case,
+    ) -> heapless::String<CAP> {
+        const { assert!(CAP >= 3 * N - 1, "capacity too small for the address") };
+        let mut s = heapless::String::new();
+        // The capacity is enough for any format.
+        let _ = self.write_format_with(&mut s, format, case);
+        s
+    }
+
+    /// Formats the address as `0A-1B-2C-3D-4E-5F`; see
+    /// [`to_heapless`](Eui::to_heapless).
+    pub fn to_canonical_heapless<const CAP: usize>(&self) -> heapless::String<CAP> {
+        self.to_heapless(Format::Canonical, Case::Upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Eui, Format};
+
+    #[test]
+    fn to_heapless() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let s = eui.to_canonical_heapless::<17>();
+        assert_eq!(s, "0A-1B-2C-3D-4E-5F");
+        let s = eui.to_heapless::<32>(Format::Dot, Case::Lower);
+        assert_eq!(s, "0a1b.2c3d.4e5f");
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let s = eui.to_heapless::<23>(Format::Colon, Case::Upper);
+        assert_eq!(s, "00:FF:0A:1B:2C:3D:4E:5F");
+    }
+}
@@ -0,0 +1,215 @@
+//! IPv6 addresses whose interface identifier is a modified EUI-64
+//! (RFC 4291, appendix A), as built by link-local and SLAAC configuration,
+//! and the `33-33` mapping of IPv6 multicast groups (RFC 2464, section 7).
+
+use crate::{Error, Eui, EUI48, EUI64};
+use core::net::Ipv6Addr;
+
+const LINK_LOCAL_PREFIX: Ipv6Addr = Ipv6Addr::new(0xFE80, 0, 0, 0, 0, 0, 0, 0);
+
+/// Joins the upper 64 bits of `prefix` with an interface identifier.
+fn with_iid(prefix: &Ipv6Addr, iid: [u8; 8]) -> Ipv6Addr {
+    let mut octets = prefix.octets();
+    octets[8..].copy_from_slice(&iid);
+    Ipv6Addr::from(octets)
+}
+
+/// Returns the EUI-64 that `addr`'s interface identifier was built from.
+fn unmodified_iid(addr: &Ipv6Addr) -> EUI64 {
+    let mut raw_address: [u8; 8] = Default::default();
+    raw_address.copy_from_slice(&addr.octets()[8..]);
+    raw_address[0] ^= 0x02;
+    Eui(raw_address)
+}
+
+impl EUI48 {
+    /// Returns the `fe80::/64` address built from the modified EUI-64.
+    pub fn to_link_local_ipv6(&self) -> Ipv6Addr {
+        with_iid(&LINK_LOCAL_PREFIX, self.modified_eui64_bytes())
+    }
+
+    /// Returns the SLAAC address for the /64 `prefix`; any bits of `prefix`
+    /// past the first 64 are replaced by the modified EUI-64.
+    pub fn to_slaac_ipv6(&self, prefix: Ipv6Addr) -> Ipv6Addr {
+        with_iid(&prefix, self.modified_eui64_bytes())
+    }
+
+    /// Recovers the EUI-48 behind a SLAAC or link-local address, if its
+    /// interface identifier has the FF-FE marker of an expanded EUI-48.
+    /// Addresses with random or otherwise opaque identifiers give `None`.
+    pub fn from_slaac_ipv6(addr: &Ipv6Addr) -> Option<EUI48> {
+        unmodified_iid(addr)
+            .to_eui48()
+            .filter(|_| addr.octets()[11..13] == [0xFF, 0xFE])
+    }
+
+    /// Maps an IPv6 multicast group to its `33-33` address, which carries the
+    /// low 32 bits of the group.
+    pub fn from_ipv6_multicast(group: Ipv6Addr) -> Result<EUI48, Error> {
+        if !group.is_multicast() {
+            return Err(Error::NotMulticast);
+        }
+
+        let o = group.octets();
+        Ok(Eui([0x33, 0x33, o[12], o[13], o[14], o[15]]))
+    }
+
+    /// Returns the address that Neighbor Discovery solicitations for
+    /// `target` are sent to: the mapping of its solicited-node group
+    /// `ff02::1:ffXX:XXXX` (RFC 4291, section 2.7.1).
+    pub fn solicited_node_mac(target: Ipv6Addr) -> EUI48 {
+        let o = target.octets();
+        Eui([0x33, 0x33, 0xFF, o[13], o[14], o[15]])
+    }
+}
+
+impl EUI64 {
+    /// Returns the `fe80::/64` address whose interface identifier is the
+    /// modified EUI-64.
+    pub fn to_ipv6_link_local(&self) -> Ipv6Addr {
+        with_iid(&LINK_LOCAL_PREFIX, self.modified_eui64_bytes())
+    }
+
+    /// Returns the SLAAC address for the /64 `prefix`; any bits of `prefix`
+    /// past the first 64 are replaced by the modified EUI-64.
+    pub fn to_slaac_ipv6(&self, prefix: Ipv6Addr) -> Ipv6Addr {
+        with_iid(&prefix, self.modified_eui64_bytes())
+    }
+
+    /// Recovers the EUI-64 behind a SLAAC address. Nothing in an interface
+    /// identifier marks it as EUI-64 based, so this only undoes the U/L bit
+    /// inversion; it is up to the caller to know the address was built that
+    /// way.
+    pub fn from_slaac_ipv6(addr: &Ipv6Addr) -> EUI64 {
+        unmodified_iid(addr)
+    }
+
+    pub fn from_ipv6_link_local(addr: &Ipv6Addr) -> Result<EUI64, Error> {
+        if addr.octets()[..8] != LINK_LOCAL_PREFIX.octets()[..8] {
+            return Err(Error::NotLinkLocal);
+        }
+
+        Ok(unmodified_iid(addr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Eui, EUI48, EUI64};
+    use core::net::Ipv6Addr;
+
+    #[test]
+    fn eui48_to_link_local_ipv6() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.to_link_local_ipv6(),
+            "fe80::81b:2cff:fe3d:4e5f".parse::<Ipv6Addr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn eui48_to_slaac_ipv6() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let prefix = "2001:db8:1:2::".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(
+            eui.to_slaac_ipv6(prefix),
+            "2001:db8:1:2:81b:2cff:fe3d:4e5f"
+                .parse::<Ipv6Addr>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn eui48_to_slaac_ipv6_ignores_low_prefix_bits() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let prefix = "2001:db8:1:2::1".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(
+            eui.to_slaac_ipv6(prefix),
+            "2001:db8:1:2:81b:2cff:fe3d:4e5f"
+                .parse::<Ipv6Addr>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn eui64_to_ipv6_link_local() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.to_ipv6_link_local(),
+            "fe80::2ff:a1b:2c3d:4e5f".parse::<Ipv6Addr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn eui64_to_slaac_ipv6() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let prefix = "2001:db8::".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(
+            eui.to_slaac_ipv6(prefix),
+            "2001:db8::2ff:a1b:2c3d:4e5f".parse::<Ipv6Addr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn eui64_from_ipv6_link_local() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            EUI64::from_ipv6_link_local(&eui.to_ipv6_link_local()),
+            Ok(eui)
+        );
+    }
+
+    #[test]
+    fn eui64_from_ipv6_link_local_bad_prefix() {
+        let addr = "2001:db8::2ff:a1b:2c3d:4e5f".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(EUI64::from_ipv6_link_local(&addr), Err(Error::NotLinkLocal));
+    }
+
+    #[test]
+    fn eui48_from_slaac_ipv6() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let prefix = "2001:db8:1:2::".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(
+            EUI48::from_slaac_ipv6(&eui.to_slaac_ipv6(prefix)),
+            Some(eui)
+        );
+        assert_eq!(EUI48::from_slaac_ipv6(&eui.to_link_local_ipv6()), Some(eui));
+    }
+
+    #[test]
+    fn eui48_from_slaac_ipv6_opaque() {
+        let addr = "2001:db8::2ff:a1b:2c3d:4e5f".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(EUI48::from_slaac_ipv6(&addr), None);
+        // FF-FF in the middle is MAC-48 encapsulation, which SLAAC never uses.
+        let addr = "2001:db8::81b:2cff:ff3d:4e5f".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(EUI48::from_slaac_ipv6(&addr), None);
+    }
+
+    #[test]
+    fn eui64_from_slaac_ipv6() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let prefix = "2001:db8::".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(EUI64::from_slaac_ipv6(&eui.to_slaac_ipv6(prefix)), eui);
+    }
+
+    #[test]
+    fn eui48_from_ipv6_multicast() {
+        let group = "ff02::1".parse::<Ipv6Addr>().unwrap();
+        let eui = EUI48::from_ipv6_multicast(group).unwrap();
+        assert_eq!(eui, [0x33, 0x33, 0x00, 0x00, 0x00, 0x01]);
+        let group = "ff05::1:3".parse::<Ipv6Addr>().unwrap();
+        let eui = EUI48::from_ipv6_multicast(group).unwrap();
+        assert_eq!(eui, [0x33, 0x33, 0x00, 0x01, 0x00, 0x03]);
+        let addr = "2001:db8::1".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(EUI48::from_ipv6_multicast(addr), Err(Error::NotMulticast));
+    }
+
+    #[test]
+    fn eui48_solicited_node_mac() {
+        let target = "2001:db8::81b:2cff:fe3d:4e5f".parse::<Ipv6Addr>().unwrap();
+        let eui = EUI48::solicited_node_mac(target);
+        assert_eq!(eui, [0x33, 0x33, 0xFF, 0x3D, 0x4E, 0x5F]);
+        let group = "ff02::1:ff3d:4e5f".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(EUI48::from_ipv6_multicast(group), Ok(eui));
+    }
+}
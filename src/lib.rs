@@ -1,235 +1,2274 @@
-#[derive(Debug, PartialEq)]
-pub struct EUI48([u8; 6]);
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-#[derive(Debug, PartialEq)]
-pub struct EUI64([u8; 8]);
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::convert::TryFrom;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+use core::net::Ipv4Addr;
+
+mod addr;
+mod arith;
+mod block;
+mod bluetooth;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+#[cfg(feature = "clap")]
+pub mod cli;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+mod dhcp;
+mod ethernet;
+mod format;
+#[cfg(feature = "heapless")]
+mod heapless_impls;
+mod ipv6;
+mod lorawan;
+mod ops;
+mod options;
+mod origin;
+#[cfg(feature = "os")]
+mod os;
+mod oui;
+#[cfg(feature = "vendors")]
+pub mod oui_db;
+mod parser;
+mod prefix;
+#[cfg(feature = "alloc")]
+mod prefix_map;
+mod radius;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_dot;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod slap;
+#[cfg(feature = "ufmt")]
+mod ufmt_impls;
+#[cfg(feature = "uuid")]
+mod uuid_node;
+#[cfg(feature = "alloc")]
+mod vendor_db;
+mod well_known;
+mod wol;
+mod wwn;
+
+pub use addr::EuiAddr;
+pub use block::{MaL, MaM, MaS};
+pub use bluetooth::BdAddr;
+pub use format::{detect_format, Case, Format};
+pub use lorawan::{DevEui, JoinEui};
+pub use options::ParseOptions;
+pub use origin::AddressOrigin;
+pub use oui::Oui;
+#[cfg(feature = "oui-db")]
+pub use oui_db::vendor_histogram;
+pub use prefix::EuiPrefix;
+#[cfg(feature = "alloc")]
+pub use prefix_map::PrefixMap;
+pub use radius::StationIdProfile;
+pub use range::{Eui48Range, Eui64Range};
+pub use slap::{Cid, SlapQuadrant};
+#[cfg(feature = "alloc")]
+pub use vendor_db::VendorDb;
+pub use well_known::WellKnown;
+pub use wwn::{Guid, Naa, Wwn};
+
+/// Builds an [`EUI48`] from a string literal, which is validated at compile
+/// time.
+///
+/// ```
+/// const GATEWAY: eui::EUI48 = eui::eui48!("0A-1B-2C-3D-4E-5F");
+/// assert_eq!(GATEWAY.octets(), [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+/// ```
+///
+/// ```compile_fail
+/// let typo = eui::eui48!("0A-1B-2C-3D-4E-5G");
+/// ```
+#[macro_export]
+macro_rules! eui48 {
+    ($s:literal) => {{
+        const EUI: $crate::EUI48 = match $crate::EUI48::parse_const($s) {
+            ::core::result::Result::Ok(eui) => eui,
+            ::core::result::Result::Err(_) => ::core::panic!(concat!("invalid EUI-48: ", $s)),
+        };
+        EUI
+    }};
+}
+
+/// Builds an [`EUI64`] from a string literal, which is validated at compile
+/// time.
+///
+/// ```
+/// const NODE: eui::EUI64 = eui::eui64!("00-FF-0A-1B-2C-3D-4E-5F");
+/// assert_eq!(NODE.octets(), [0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+/// ```
+///
+/// ```compile_fail
+/// let typo = eui::eui64!("00-FF-0A-1B-2C-3D-4E");
+/// ```
+#[macro_export]
+macro_rules! eui64 {
+    ($s:literal) => {{
+        const EUI: $crate::EUI64 = match $crate::EUI64::parse_const($s) {
+            ::core::result::Result::Ok(eui) => eui,
+            ::core::result::Result::Err(_) => ::core::panic!(concat!("invalid EUI-64: ", $s)),
+        };
+        EUI
+    }};
+}
+
+/// An address of `N` octets. The two widths in use share this type so that
+/// they behave the same; use it through [`EUI48`] and [`EUI64`]. Other widths
+/// are rejected by the [`Width`] bound:
+///
+/// ```compile_fail
+/// let eui = eui::Eui::from([0x01; 12]);
+/// ```
+///
+/// The layout is guaranteed to be that of `[u8; N]`, so addresses can be
+/// borrowed in place from packet buffers, e.g. the two leading fields of an
+/// Ethernet header, with [`Eui::from_ref`] and [`Eui::from_slice`] or through
+/// the `zerocopy` and `bytemuck` features.
+// Ord compares the bytes in order, which matches big-endian numeric order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct Eui<const N: usize>([u8; N])
+where
+    [u8; N]: Width;
+
+/// The widths an [`Eui`] comes in, implemented for the octet arrays of
+/// [`EUI48`] and [`EUI64`] only. It can't be implemented outside this crate.
+pub trait Width: sealed::Sealed {}
+
+impl Width for [u8; 6] {}
+
+impl Width for [u8; 8] {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for [u8; 6] {}
+
+    impl Sealed for [u8; 8] {}
+}
+
+pub type EUI48 = Eui<6>;
+
+pub type EUI64 = Eui<8>;
+
+/// Assembles an [`EUI64`] from an OUI and a 40-bit extension, both zero
+/// unless set; see [`EUI64::builder`].
+#[derive(Debug, Default)]
+pub struct Eui64Builder {
+    oui: [u8; 3],
+    extension: [u8; 5],
+}
+
+/// A formatted address stored inline, without heap allocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EuiStr<const N: usize>([u8; N]);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Error {
-    InvalidHexCharacter,
+    /// `c`, at byte `index` of the input, is not a hex digit.
+    InvalidHexCharacter {
+        c: char,
+        index: usize,
+    },
+    /// `c`, at byte `index` of the input, is not the separator expected
+    /// there.
+    InvalidSeparator {
+        c: char,
+        index: usize,
+    },
     InvalidStringLength,
     OddLength,
+    InvalidPrefix,
+    NotLinkLocal,
+    NotMulticast,
+    OutOfRange,
+    NonUtf8,
+    InvalidSliceLength,
+    UnknownProfile,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Error::InvalidHexCharacter { c, index } => {
+                return write!(f, "invalid hex character {:?} at index {}", c, index)
+            }
+            Error::InvalidSeparator { c, index } => {
+                return write!(f, "invalid separator {:?} at index {}", c, index)
+            }
+            Error::InvalidStringLength => "invalid string length",
+            Error::OddLength => "odd number of hex digits",
+            Error::InvalidPrefix => "invalid prefix",
+            Error::NotLinkLocal => "not an fe80::/64 link-local address",
+            Error::NotMulticast => "not a multicast address",
+            Error::OutOfRange => "value out of range",
+            Error::NonUtf8 => "input is not valid UTF-8",
+            Error::InvalidSliceLength => "invalid slice length",
+            Error::UnknownProfile => "unknown formatting profile",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(eui_core_error)]
+impl core::error::Error for Error {}
+
+#[cfg(all(not(eui_core_error), feature = "std"))]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "alloc")]
+impl From<hex::FromHexError> for Error {
+    fn from(e: hex::FromHexError) -> Self {
+        match e {
+            hex::FromHexError::InvalidHexCharacter { c, index } => {
+                Error::InvalidHexCharacter { c, index }
+            }
+            hex::FromHexError::InvalidStringLength => Error::InvalidStringLength,
+            hex::FromHexError::OddLength => Error::OddLength,
+        }
+    }
+}
+
+pub trait EUI {
+    fn to_bytes(&self) -> &[u8];
+
+    /// Length of `to_canonical_fmt` without building the string.
+    fn canonical_width(&self) -> usize {
+        3 * self.to_bytes().len() - 1
+    }
+
+    /// Writes the address as `0A-1B-2C-3D-4E-5F`.
+    fn write_canonical(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        format::write_grouped(self.to_bytes(), w, Format::Canonical, Case::Upper)
+    }
+
+    /// Writes the address as `0A:1B:2C:3D:4E:5F`.
+    fn write_colon(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        format::write_grouped(self.to_bytes(), w, Format::Colon, Case::Upper)
+    }
+
+    /// Writes the address as `0A 1B 2C 3D 4E 5F`.
+    fn write_spaced(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        format::write_grouped(self.to_bytes(), w, Format::Spaced, Case::Upper)
+    }
+
+    /// Writes the address as `0A1B.2C3D.4E5F`.
+    fn write_dot(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        format::write_grouped(self.to_bytes(), w, Format::Dot, Case::Upper)
+    }
+
+    /// Writes the address as `0A1B2C3D4E5F`.
+    fn write_bare(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        format::write_grouped(self.to_bytes(), w, Format::Bare, Case::Upper)
+    }
+
+    fn write_format(&self, w: &mut impl core::fmt::Write, format: Format) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        self.write_format_with(w, format, Case::Upper)
+    }
+
+    fn write_format_with(
+        &self,
+        w: &mut impl core::fmt::Write,
+        format: Format,
+        case: Case,
+    ) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        format::write_grouped(self.to_bytes(), w, format, case)
+    }
+
+    /// Formats the address canonically into the start of `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`EUI::canonical_width`].
+    fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+        let buf = &mut buf[..self.canonical_width()];
+        for (i, b) in self.to_bytes().iter().enumerate() {
+            if i > 0 {
+                buf[3 * i - 1] = b'-';
+            }
+            buf[3 * i] = DIGITS[usize::from(b >> 4)];
+            buf[3 * i + 1] = DIGITS[usize::from(b & 0x0F)];
+        }
+        // Only ever filled with ASCII hex digits and separators.
+        core::str::from_utf8(buf).unwrap_or_default()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_fmt(&self, format: Format) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_format(s, format))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_fmt_with(&self, format: Format, case: Case) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_format_with(s, format, case))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_canonical_fmt(&self) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_canonical(s))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_colon_fmt(&self) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_colon(s))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_spaced_fmt(&self) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_spaced(s))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_dot_fmt(&self) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_dot(s))
+    }
+
+    /// Returns the address as `0A1B2C3D4E5F`; use [`EUI::to_fmt_with`] for
+    /// lowercase.
+    #[cfg(feature = "alloc")]
+    fn to_bare_fmt(&self) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with(|s| self.write_bare(s))
+    }
+
+    /// Returns a key that lets both widths share one `u64`-keyed map.
+    ///
+    /// An EUI-64 maps to its big-endian value. An EUI-48 maps to its value
+    /// with the top 16 bits set to `0xFFFF`, which only collides with
+    /// EUI-64s starting with `FF-FF`, a group address prefix that is never
+    /// assigned.
+    fn key(&self) -> u64 {
+        let bytes = self.to_bytes();
+        let key = bytes.iter().fold(0, |acc, b| acc << 8 | u64::from(*b));
+        if bytes.len() == 6 {
+            key | 0xFFFF << 48
+        } else {
+            key
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn to_string_with(write: impl FnOnce(&mut String) -> core::fmt::Result) -> String {
+    let mut s = String::new();
+    // Writing to a String never fails.
+    let _ = write(&mut s);
+    s
+}
+
+impl<const N: usize> EUI for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> Eui<N>
+where
+    [u8; N]: Width,
+{
+    pub const BROADCAST: Self = Eui([0xFF; N]);
+    pub const NIL: Self = Eui([0x00; N]);
+
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Eui(bytes)
+    }
+
+    pub const fn octets(&self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns the octets in transmission order, the same as [`Eui::octets`].
+    pub const fn to_be_bytes(&self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns the octets in reverse order, as some hardware registers and
+    /// protocols store them.
+    pub const fn to_le_bytes(&self) -> [u8; N] {
+        let mut bytes = self.0;
+        let mut i = 0;
+        while i < N / 2 {
+            let t = bytes[i];
+            bytes[i] = bytes[N - 1 - i];
+            bytes[N - 1 - i] = t;
+            i += 1;
+        }
+        bytes
+    }
+
+    pub const fn into_array(self) -> [u8; N] {
+        self.0
+    }
+
+    pub const fn from_be_bytes(bytes: [u8; N]) -> Self {
+        Eui(bytes)
+    }
+
+    pub const fn from_le_bytes(bytes: [u8; N]) -> Self {
+        Eui(Eui(bytes).to_le_bytes())
+    }
+
+    /// Views `bytes` as an address without copying them.
+    pub const fn from_ref(bytes: &[u8; N]) -> &Self {
+        // SAFETY: `Eui<N>` is `repr(transparent)` over `[u8; N]`.
+        unsafe { &*(bytes as *const [u8; N] as *const Self) }
+    }
+
+    /// Views `bytes` as an address without copying them, failing with
+    /// `InvalidSliceLength` unless there are exactly `N` of them.
+    pub fn from_slice(bytes: &[u8]) -> Result<&Self, Error> {
+        <&[u8; N]>::try_from(bytes)
+            .map(Eui::from_ref)
+            .map_err(|_| Error::InvalidSliceLength)
+    }
+
+    /// Same as `TryFrom<&str>`, but usable in const contexts.
+    pub const fn parse_const(s: &str) -> Result<Self, Error> {
+        match parser::decode(s, true) {
+            Ok(bytes) => Ok(Eui(bytes)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses `s` under `options`, which may also be just the [`Format`] it
+    /// must be laid out in.
+    pub fn parse_with(s: &str, options: impl Into<ParseOptions>) -> Result<Self, Error> {
+        options.into().decode(s).map(Eui)
+    }
+
+    /// Parses `s`, which must use one [`Format`] consistently, unlike
+    /// `TryFrom<&str>` which accepts separators anywhere.
+    pub fn parse_strict(s: &str) -> Result<Self, Error> {
+        format::parse_strict(s).map(Eui)
+    }
+
+    pub fn is_broadcast(&self) -> bool {
+        *self == Self::BROADCAST
+    }
+
+    pub fn is_nil(&self) -> bool {
+        *self == Self::NIL
+    }
+
+    /// Returns whether the I/G bit is set, i.e. the address names a group.
+    pub const fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    pub const fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    pub const fn is_universally_administered(&self) -> bool {
+        !self.is_locally_administered()
+    }
+
+    /// Returns whether the U/L bit is set, i.e. the address was not assigned
+    /// under an IEEE-registered OUI.
+    pub const fn is_locally_administered(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+
+    /// Returns a copy of the address with the U/L bit set to `local`.
+    pub const fn with_local_bit(&self, local: bool) -> Self {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !0x02 | (local as u8) << 1;
+        Eui(bytes)
+    }
+
+    /// Returns a copy of the address with the I/G bit set to `multicast`.
+    pub const fn with_multicast_bit(&self, multicast: bool) -> Self {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !0x01 | multicast as u8;
+        Eui(bytes)
+    }
+
+    pub fn set_local_bit(&mut self, local: bool) {
+        *self = self.with_local_bit(local);
+    }
+
+    pub fn set_multicast_bit(&mut self, multicast: bool) {
+        *self = self.with_multicast_bit(multicast);
+    }
+
+    /// Returns the address as a big-endian integer.
+    pub fn to_u64(&self) -> u64 {
+        self.0.iter().fold(0, |acc, b| acc << 8 | u64::from(*b))
+    }
+
+    pub fn complement(&self) -> Self {
+        !*self
+    }
+}
+
+impl EUI48 {
+    /// Expands the address to an EUI-64 by inserting FF-FE between the OUI
+    /// and the device portion.
+    pub fn to_eui64(&self) -> EUI64 {
+        let b = &self.0;
+        Eui([b[0], b[1], b[2], 0xFF, 0xFE, b[3], b[4], b[5]])
+    }
+
+    /// Expands the address to the modified EUI-64 used for IPv6 interface
+    /// identifiers (RFC 4291, appendix A): FF-FE inserted, U/L bit inverted.
+    pub fn to_modified_eui64(&self) -> EUI64 {
+        Eui(self.to_eui64().modified_eui64_bytes())
+    }
+
+    /// Returns the modified EUI-64 (FF-FE inserted, U/L bit inverted).
+    pub fn modified_eui64_bytes(&self) -> [u8; 8] {
+        self.to_modified_eui64().0
+    }
+
+    /// Returns the IPv6-over-Bluetooth LE interface identifier (RFC 7668,
+    /// section 3.2.2): FF-FE is inserted but, unlike the modified EUI-64, no
+    /// bit of the device address is changed.
+    pub fn to_rfc7668_iid(&self) -> [u8; 8] {
+        self.to_eui64().0
+    }
+
+    /// Checks membership in a block written as `<address>/<prefix length>`,
+    /// e.g. `00-1B-44-00-00-00/28`.
+    pub fn in_block(&self, block: &str) -> Result<bool, Error> {
+        Ok(EuiPrefix::try_from(block)?.matches(self))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Parses an address followed by an optional `%<iface>` scope suffix. A
+    /// `%` with no scope after it fails with `InvalidStringLength`.
+    pub fn parse_with_scope(s: &str) -> Result<(EUI48, Option<String>), Error> {
+        match s.split_once('%') {
+            Some((address, scope)) => {
+                let eui = EUI48::try_from(address)?;
+                if scope.is_empty() {
+                    return Err(Error::InvalidStringLength);
+                }
+                Ok((eui, Some(scope.to_string())))
+            }
+            None => Ok((EUI48::try_from(s)?, None)),
+        }
+    }
+
+    /// Parses an address whose single-digit groups have dropped their leading
+    /// zero, e.g. `A-1B-2C-3D-4E-5F`.
+    pub fn from_loose_fmt(s: &str) -> Result<EUI48, Error> {
+        parser::decode_loose(s).map(Eui)
+    }
+
+    /// Returns an IPv4 multicast group that maps to this address.
+    ///
+    /// Only the low 23 bits of a group survive the `01-00-5E` mapping, so 32
+    /// groups share every such address. The one returned has the five
+    /// ambiguous bits cleared, i.e. it lies within `224.0.0.0/9`.
+    pub fn as_ipv4_multicast(&self) -> Option<Ipv4Addr> {
+        let b = &self.0;
+        if b[..3] != [0x01, 0x00, 0x5E] || b[3] & 0x80 != 0 {
+            return None;
+        }
+
+        Some(Ipv4Addr::new(224, b[3], b[4], b[5]))
+    }
+
+    /// Maps an IPv4 multicast group to its `01-00-5E` address, which carries
+    /// the low 23 bits of the group (RFC 1112, section 6.4).
+    pub fn from_ipv4_multicast(group: Ipv4Addr) -> Result<EUI48, Error> {
+        if !group.is_multicast() {
+            return Err(Error::NotMulticast);
+        }
+
+        let o = group.octets();
+        Ok(Eui([0x01, 0x00, 0x5E, o[1] & 0x7F, o[2], o[3]]))
+    }
+
+    /// Returns all 32 IPv4 groups that map to this address, in ascending
+    /// order, or `None` if it is not an IPv4 multicast address.
+    pub fn ipv4_multicast_groups(&self) -> Option<[Ipv4Addr; 32]> {
+        let base = self.as_ipv4_multicast()?.octets();
+        let mut groups = [Ipv4Addr::UNSPECIFIED; 32];
+        for (i, group) in groups.iter_mut().enumerate() {
+            let i = i as u8;
+            *group = Ipv4Addr::new(224 | i >> 1, base[1] | (i & 1) << 7, base[2], base[3]);
+        }
+        Some(groups)
+    }
+
+    /// Builds the multicast address for `group` under `oui`, placing the
+    /// group number in the low 24 bits.
+    pub fn group_multicast(oui: [u8; 3], group: u32) -> Result<EUI48, Error> {
+        EUI48::from_oui_and_u24([oui[0] | 0x01, oui[1], oui[2]], group)
+    }
+
+    /// Combines an OUI with a 24-bit device number.
+    pub fn from_oui_and_u24(oui: [u8; 3], low: u32) -> Result<EUI48, Error> {
+        if low > 0xFF_FFFF {
+            return Err(Error::OutOfRange);
+        }
+
+        let l = low.to_be_bytes();
+        Ok(Eui([oui[0], oui[1], oui[2], l[1], l[2], l[3]]))
+    }
+
+    /// Returns whether the address may be handed out by a local allocator:
+    /// locally administered, unicast, and neither nil nor broadcast.
+    pub fn is_valid_assignable(&self) -> bool {
+        self.is_locally_administered()
+            && self.is_unicast()
+            && !self.is_nil()
+            && !self.is_broadcast()
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Returns the address as lowercase hex without separators, which is safe
+    /// to use as a file name on any file system.
+    pub fn to_file_key(&self) -> String {
+        self.to_fmt_with(Format::Bare, Case::Lower)
+    }
+
+    /// Builds an address from a big-endian integer, which must fit in 48
+    /// bits.
+    pub fn from_u64(value: u64) -> Result<EUI48, Error> {
+        if value > 0xFFFF_FFFF_FFFF {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut raw_address: [u8; 6] = Default::default();
+        raw_address.copy_from_slice(&value.to_be_bytes()[2..]);
+        Ok(Eui(raw_address))
+    }
+
+    /// Returns the number of addresses numerically above this one.
+    pub fn remaining(&self) -> u64 {
+        0xFFFF_FFFF_FFFF - self.to_u64()
+    }
+
+    /// Returns whether the two addresses are at most `tolerance` apart
+    /// numerically.
+    pub fn within(&self, other: &EUI48, tolerance: u64) -> bool {
+        let (a, b) = (self.to_u64(), other.to_u64());
+        a.max(b) - a.min(b) <= tolerance
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Formats the address canonically with the device portion (the last
+    /// three octets) replaced by `XX`.
+    pub fn redacted_fmt(&self) -> String {
+        self.redacted_fmt_with(3)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Formats the address canonically with the last `octets` octets
+    /// replaced by `XX`.
+    pub fn redacted_fmt_with(&self, octets: usize) -> String {
+        let shown = self.0.len().saturating_sub(octets);
+        to_string_with(|s| {
+            for (i, b) in self.0.iter().enumerate() {
+                if i > 0 {
+                    s.push('-');
+                }
+                if i < shown {
+                    write!(s, "{:02X}", b)?;
+                } else {
+                    s.push_str("XX");
+                }
+            }
+            Ok(())
+        })
+    }
+
+    pub fn to_canonical_stack(&self) -> EuiStr<17> {
+        EuiStr::canonical(&self.0)
+    }
+
+    /// Returns whether the address is one of the IEEE 802.1 reserved group
+    /// addresses `01-80-C2-00-00-00` to `01-80-C2-00-00-0F`, which bridges
+    /// do not forward.
+    pub fn is_reserved_group(&self) -> bool {
+        self.0[..5] == [0x01, 0x80, 0xC2, 0x00, 0x00] && self.0[5] <= 0x0F
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Formats the address per the SNMP `MacAddress` textual convention
+    /// (lowercase, colon separated).
+    pub fn to_snmp_fmt(&self) -> String {
+        self.to_fmt_with(Format::Colon, Case::Lower)
+    }
+
+    pub fn from_snmp_fmt(s: &str) -> Result<EUI48, Error> {
+        EUI48::try_from(s)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Formats the address as lowercase hex without separators, for use in
+    /// URL paths.
+    pub fn to_url_fmt(&self) -> String {
+        self.to_fmt_with(Format::Bare, Case::Lower)
+    }
+
+    /// Parses the output of [`EUI48::to_url_fmt`]; separators are rejected.
+    pub fn from_url_fmt(s: &str) -> Result<EUI48, Error> {
+        parser::decode(s, false).map(Eui)
+    }
+
+    pub fn matches_any_oui(&self, ouis: &[[u8; 3]]) -> bool {
+        ouis.iter().any(|oui| oui[..] == self.0[..3])
+    }
+
+    /// Returns the bits of the address, most significant bit first.
+    pub fn to_bits(&self) -> [bool; 48] {
+        let mut bits = [false; 48];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = self.0[i / 8] & (0x80 >> (i % 8)) != 0;
+        }
+        bits
+    }
+
+    pub fn from_bits(bits: &[bool; 48]) -> EUI48 {
+        let mut raw_address: [u8; 6] = Default::default();
+        for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+            raw_address[i / 8] |= 0x80 >> (i % 8);
+        }
+        Eui(raw_address)
+    }
+}
+
+impl EUI64 {
+    /// Starts an [`Eui64Builder`].
+    pub fn builder() -> Eui64Builder {
+        Eui64Builder::default()
+    }
+
+    /// Recovers the EUI-48 encapsulated in this address, if its middle bytes
+    /// are FF-FE (EUI-48 encapsulation) or FF-FF (the older MAC-48 one).
+    pub fn to_eui48(&self) -> Option<EUI48> {
+        let b = &self.0;
+        match [b[3], b[4]] {
+            [0xFF, 0xFE] | [0xFF, 0xFF] => Some(Eui([b[0], b[1], b[2], b[5], b[6], b[7]])),
+            _ => None,
+        }
+    }
+
+    /// Returns the modified EUI-64 (U/L bit inverted).
+    pub fn modified_eui64_bytes(&self) -> [u8; 8] {
+        let mut bytes = self.0;
+        bytes[0] ^= 0x02;
+        bytes
+    }
+
+    pub fn to_canonical_stack(&self) -> EuiStr<23> {
+        EuiStr::canonical(&self.0)
+    }
+
+    pub fn from_u64(value: u64) -> EUI64 {
+        Eui(value.to_be_bytes())
+    }
+
+    pub fn remaining(&self) -> u64 {
+        u64::MAX - self.to_u64()
+    }
+
+    /// Swaps the high and low 32-bit words.
+    pub fn swap_words(&self) -> EUI64 {
+        let mut raw_address = self.0;
+        raw_address.rotate_left(4);
+        Eui(raw_address)
+    }
+
+    /// Computes the Maxim/Dallas CRC-8 of the first seven bytes, as used by
+    /// 1-Wire ROM codes.
+    pub fn onewire_crc(&self) -> u8 {
+        self.0[..7].iter().fold(0, |crc, byte| {
+            (0..8).fold(crc ^ byte, |crc, _| {
+                if crc & 0x01 != 0 {
+                    (crc >> 1) ^ 0x8C
+                } else {
+                    crc >> 1
+                }
+            })
+        })
+    }
+
+    pub fn onewire_crc_valid(&self) -> bool {
+        self.onewire_crc() == self.0[7]
+    }
+
+    /// Returns the bits of the address, most significant bit first.
+    pub fn to_bits(&self) -> [bool; 64] {
+        let mut bits = [false; 64];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = self.0[i / 8] & (0x80 >> (i % 8)) != 0;
+        }
+        bits
+    }
+
+    pub fn from_bits(bits: &[bool; 64]) -> EUI64 {
+        let mut raw_address: [u8; 8] = Default::default();
+        for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+            raw_address[i / 8] |= 0x80 >> (i % 8);
+        }
+        Eui(raw_address)
+    }
+}
+
+impl<const N: usize> EuiStr<N> {
+    fn canonical(bytes: &[u8]) -> Self {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+        let mut buf = [b'-'; N];
+        for (i, b) in bytes.iter().enumerate() {
+            buf[3 * i] = DIGITS[usize::from(b >> 4)];
+            buf[3 * i + 1] = DIGITS[usize::from(b & 0x0F)];
+        }
+        EuiStr(buf)
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only ever filled with ASCII hex digits and separators.
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> core::ops::Deref for EuiStr<N> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Eui64Builder {
+    /// Sets the first three octets.
+    pub fn oui(mut self, oui: [u8; 3]) -> Self {
+        self.oui = oui;
+        self
+    }
+
+    /// Sets the last five octets.
+    pub fn extension(mut self, extension: [u8; 5]) -> Self {
+        self.extension = extension;
+        self
+    }
+
+    /// Returns the OUI followed by the extension.
+    pub fn build(self) -> EUI64 {
+        let mut raw_address: [u8; 8] = Default::default();
+        raw_address[..3].copy_from_slice(&self.oui);
+        raw_address[3..].copy_from_slice(&self.extension);
+        Eui(raw_address)
+    }
 }
 
-impl From<hex::FromHexError> for Error {
-    fn from(e: hex::FromHexError) -> Self {
-        match e {
-            hex::FromHexError::InvalidHexCharacter { .. } => Error::InvalidHexCharacter,
-            hex::FromHexError::InvalidStringLength => Error::InvalidStringLength,
-            hex::FromHexError::OddLength => Error::OddLength,
-        }
+// Default is the nil address.
+impl<const N: usize> Default for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn default() -> Self {
+        Self::NIL
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<Eui<N>> for [u8; N]
+where
+    [u8; N]: Width,
+{
+    fn eq(&self, other: &Eui<N>) -> bool {
+        *self == other.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn from(bytes: [u8; N]) -> Self {
+        Eui(bytes)
+    }
+}
+
+impl<const N: usize> From<Eui<N>> for [u8; N]
+where
+    [u8; N]: Width,
+{
+    fn from(eui: Eui<N>) -> Self {
+        eui.0
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<&[u8]> for Eui<N>
+where
+    [u8; N]: Width,
+{
+    type Error = crate::Error;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; N]>::try_from(bytes)
+            .map(Eui)
+            .map_err(|_| Error::InvalidSliceLength)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<&str> for Eui<N>
+where
+    [u8; N]: Width,
+{
+    type Error = crate::Error;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Eui::parse_const(s)
+    }
+}
+
+impl<const N: usize> core::str::FromStr for Eui<N>
+where
+    [u8; N]: Width,
+{
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Eui::try_from(s)
+    }
+}
+
+/// Writes the address canonically, or with colons under `{:#}`, honoring
+/// width, fill and alignment.
+impl<const N: usize> core::fmt::Display for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0u8; 32];
+        let len = self.format_into(&mut buf).len();
+        if f.alternate() {
+            buf[..len]
+                .iter_mut()
+                .filter(|b| **b == b'-')
+                .for_each(|b| *b = b':');
+        }
+        // Only ever filled with ASCII hex digits and separators.
+        f.pad(core::str::from_utf8(&buf[..len]).unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> std::convert::TryFrom<&std::ffi::OsStr> for Eui<N>
+where
+    [u8; N]: Width,
+{
+    type Error = crate::Error;
+    fn try_from(s: &std::ffi::OsStr) -> Result<Self, Self::Error> {
+        Eui::try_from(s.to_str().ok_or(Error::NonUtf8)?)
+    }
+}
+
+/// Parses consecutive EUI-64s concatenated into one hex string.
+#[cfg(feature = "alloc")]
+pub fn parse_eui64_table(s: &str) -> Result<Vec<EUI64>, Error> {
+    let digits = || s.bytes().filter(|c| !parser::is_separator(*c));
+
+    let len = digits().count();
+    if len % 2 != 0 {
+        return Err(Error::OddLength);
+    }
+    parser::check_digits(s.as_bytes(), true)?;
+    if len % 16 != 0 {
+        return Err(Error::InvalidStringLength);
+    }
+
+    let mut table = vec![EUI64::NIL; len / 16];
+    for (i, c) in digits().enumerate() {
+        table[i / 16].0[i % 16 / 2] |= parser::hex_value(c) << (4 * (1 - i % 2));
+    }
+    Ok(table)
+}
+
+/// Collapses runs of consecutive addresses in a sorted slice into inclusive
+/// `(start, end)` pairs.
+#[cfg(feature = "alloc")]
+pub fn compress_ranges(sorted: &[EUI48]) -> Vec<(EUI48, EUI48)> {
+    let mut ranges: Vec<(EUI48, EUI48)> = Vec::new();
+    for addr in sorted {
+        match ranges.last_mut() {
+            Some((_, end)) if end.to_u64() + 1 == addr.to_u64() => *end = *addr,
+            _ => ranges.push((*addr, *addr)),
+        }
+    }
+    ranges
+}
+
+/// Returns the base address and prefix length of the smallest block that
+/// covers every address, or `None` if `addrs` is empty.
+pub fn covering_prefix(addrs: &[EUI48]) -> Option<(EUI48, u8)> {
+    let first = addrs.first()?.to_u64();
+    let diff = addrs
+        .iter()
+        .fold(0, |acc, addr| acc | (addr.to_u64() ^ first));
+    let prefix_len = (diff << 16).leading_zeros().min(48);
+    let mask = (!0u64 << (48 - prefix_len)) & 0xFFFF_FFFF_FFFF;
+
+    let b = (first & mask).to_be_bytes();
+    Some((Eui([b[2], b[3], b[4], b[5], b[6], b[7]]), prefix_len as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use crate::{compress_ranges, parse_eui64_table};
+    use crate::{covering_prefix, Error, Eui, EuiAddr, EuiStr, EUI, EUI48, EUI64};
+    use std::convert::TryFrom;
+    use std::net::Ipv4Addr;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_to_canonical_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_canonical_fmt(), "0A-1B-2C-3D-4E-5F");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_to_colon_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_colon_fmt(), "0A:1B:2C:3D:4E:5F");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_to_dot_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_dot_fmt(), "0A1B.2C3D.4E5F");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_to_canonical_fmt() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_canonical_fmt(), "00-FF-0A-1B-2C-3D-4E-5F");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_to_colon_fmt() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_colon_fmt(), "00:FF:0A:1B:2C:3D:4E:5F");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_to_dot_fmt() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_dot_fmt(), "00FF.0A1B.2C3D.4E5F");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_bare_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_bare_fmt(), "0A1B2C3D4E5F");
+        assert_eq!(EUI48::try_from(eui.to_bare_fmt().as_str()), Ok(eui));
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_bare_fmt(), "00FF0A1B2C3D4E5F");
+    }
+
+    #[test]
+    fn eui48_from_canonical_fmt() {
+        assert_eq!(
+            EUI48::try_from("0A-1B-2C-3D-4E-5F").unwrap(),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui48_from_colon_fmt() {
+        assert_eq!(
+            EUI48::try_from("0A:1B:2C:3D:4E:5F").unwrap(),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui48_from_dot_fmt() {
+        assert_eq!(
+            EUI48::try_from("0A1B.2C3D.4E5F").unwrap(),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui64_from_canonical_fmt() {
+        assert_eq!(
+            EUI64::try_from("00-FF-0A-1B-2C-3D-4E-5F").unwrap(),
+            Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui64_from_colon_fmt() {
+        assert_eq!(
+            EUI64::try_from("00:FF:0A:1B:2C:3D:4E:5F").unwrap(),
+            Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui64_from_dot_fmt() {
+        assert_eq!(
+            EUI64::try_from("00FF.0A1B.2C3D.4E5F").unwrap(),
+            Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui48_from_canonical_fmt_bad_character() {
+        assert_eq!(
+            EUI48::try_from("0A-1B-2C-3D-4x-5F"),
+            Err(Error::InvalidHexCharacter { c: 'x', index: 13 })
+        );
+    }
+
+    #[test]
+    fn eui48_from_canonical_fmt_missing_byte() {
+        assert_eq!(
+            EUI48::try_from("0A-1B-2C-3D-4E"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn eui48_from_canonical_fmt_missing_character() {
+        assert_eq!(EUI48::try_from("0A-1B-2C-3D-4E-5"), Err(Error::OddLength));
+    }
+
+    #[test]
+    fn eui64_from_canonical_fmt_bad_character() {
+        assert_eq!(
+            EUI64::try_from("00-FF-0A-1B-2C-3D-4x-5F"),
+            Err(Error::InvalidHexCharacter { c: 'x', index: 19 })
+        );
+    }
+
+    #[test]
+    fn eui64_from_canonical_fmt_missing_byte() {
+        assert_eq!(
+            EUI64::try_from("00-FF-0A-1B-2C-3D-4E"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn eui64_from_canonical_fmt_missing_character() {
+        assert_eq!(
+            EUI64::try_from("00-FF-0A-1B-2C-3D-4E-5"),
+            Err(Error::OddLength)
+        );
+    }
+
+    #[test]
+    fn eui48_modified_eui64_bytes() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.modified_eui64_bytes(),
+            [0x08, 0x1B, 0x2C, 0xFF, 0xFE, 0x3D, 0x4E, 0x5F]
+        );
+    }
+
+    #[test]
+    fn eui64_modified_eui64_bytes() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.modified_eui64_bytes(),
+            [0x02, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]
+        );
+    }
+
+    #[test]
+    fn eui64_builder() {
+        let eui = EUI64::builder()
+            .oui([0x00, 0xFF, 0x0A])
+            .extension([0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            .build();
+        assert_eq!(eui, Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+    }
+
+    #[test]
+    fn eui48_in_block() {
+        let eui = Eui([0x00, 0x1B, 0x44, 0x0A, 0xBC, 0xDE]);
+        assert_eq!(eui.in_block("00-1B-44-00-00-00/28"), Ok(true));
+    }
+
+    #[test]
+    fn eui48_not_in_block() {
+        let eui = Eui([0x00, 0x1B, 0x44, 0x1A, 0xBC, 0xDE]);
+        assert_eq!(eui.in_block("00-1B-44-00-00-00/28"), Ok(false));
+    }
+
+    #[test]
+    fn eui48_in_block_malformed() {
+        let eui = Eui([0x00, 0x1B, 0x44, 0x0A, 0xBC, 0xDE]);
+        assert_eq!(eui.in_block("00-1B-44-00-00-00"), Err(Error::InvalidPrefix));
+        assert_eq!(
+            eui.in_block("00-1B-44-00-00-00/49"),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            eui.in_block("00-1B-44-00-00/28"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn eui48_bits_round_trip() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(EUI48::from_bits(&eui.to_bits()), eui);
+    }
+
+    #[test]
+    fn eui48_to_bits_local_bit() {
+        let bits = Eui([0x02, 0x00, 0x00, 0x00, 0x00, 0x00]).to_bits();
+        assert!(bits[6]);
+        assert_eq!(bits.iter().filter(|bit| **bit).count(), 1);
+    }
+
+    #[test]
+    fn eui64_bits_round_trip() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(EUI64::from_bits(&eui.to_bits()), eui);
+    }
+
+    #[test]
+    fn eui64_to_bits_local_bit() {
+        let bits = Eui([0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]).to_bits();
+        assert!(bits[6]);
+        assert_eq!(bits.iter().filter(|bit| **bit).count(), 1);
+    }
+
+    #[test]
+    fn eui64_onewire_crc() {
+        let eui = Eui([0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA2]);
+        assert_eq!(eui.onewire_crc(), 0xA2);
+        assert!(eui.onewire_crc_valid());
+    }
+
+    #[test]
+    fn eui64_onewire_crc_invalid() {
+        let eui = Eui([0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA3]);
+        assert!(!eui.onewire_crc_valid());
+    }
+
+    #[test]
+    fn eui48_matches_any_oui() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert!(eui.matches_any_oui(&[[0x00, 0x1B, 0x44], [0x0A, 0x1B, 0x2C]]));
+    }
+
+    #[test]
+    fn eui48_matches_any_oui_absent() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert!(!eui.matches_any_oui(&[[0x00, 0x1B, 0x44], [0x0A, 0x1B, 0x2D]]));
+        assert!(!eui.matches_any_oui(&[]));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            Error::InvalidHexCharacter { c: 'G', index: 16 }.to_string(),
+            "invalid hex character 'G' at index 16"
+        );
+        assert_eq!(
+            Error::InvalidSeparator { c: '-', index: 2 }.to_string(),
+            "invalid separator '-' at index 2"
+        );
+        assert_eq!(Error::OddLength.to_string(), "odd number of hex digits");
+    }
+
+    #[cfg(any(eui_core_error, feature = "std"))]
+    #[test]
+    fn error_is_error() {
+        fn assert_error<E: std::error::Error>() {}
+        assert_error::<Error>();
+        assert!(std::error::Error::source(&Error::InvalidPrefix).is_none());
+    }
+
+    #[test]
+    fn eui48_as_ipv4_multicast() {
+        let eui = Eui([0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]);
+        assert_eq!(eui.as_ipv4_multicast(), Some(Ipv4Addr::new(224, 0, 0, 1)));
+    }
+
+    #[test]
+    fn eui48_as_ipv4_multicast_not_mapped() {
+        let eui = Eui([0x01, 0x00, 0x5E, 0x80, 0x00, 0x01]);
+        assert_eq!(eui.as_ipv4_multicast(), None);
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.as_ipv4_multicast(), None);
+    }
+
+    #[test]
+    fn eui48_from_ipv4_multicast() {
+        let eui = EUI48::from_ipv4_multicast(Ipv4Addr::new(239, 255, 255, 250)).unwrap();
+        assert_eq!(eui, [0x01, 0x00, 0x5E, 0x7F, 0xFF, 0xFA]);
+        let eui = EUI48::from_ipv4_multicast(Ipv4Addr::new(224, 128, 0, 1)).unwrap();
+        assert_eq!(eui, [0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]);
+        assert_eq!(
+            EUI48::from_ipv4_multicast(Ipv4Addr::new(192, 0, 2, 1)),
+            Err(Error::NotMulticast)
+        );
+    }
+
+    #[test]
+    fn eui48_ipv4_multicast_groups() {
+        let eui = Eui([0x01, 0x00, 0x5E, 0x7F, 0xFF, 0xFA]);
+        let groups = eui.ipv4_multicast_groups().unwrap();
+        assert_eq!(groups[0], Ipv4Addr::new(224, 127, 255, 250));
+        assert_eq!(groups[1], Ipv4Addr::new(224, 255, 255, 250));
+        assert_eq!(groups[31], Ipv4Addr::new(239, 255, 255, 250));
+        assert!(groups.windows(2).all(|w| w[0] < w[1]));
+        assert!(groups
+            .iter()
+            .all(|g| EUI48::from_ipv4_multicast(*g) == Ok(eui)));
+        assert_eq!(
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]).ipv4_multicast_groups(),
+            None
+        );
+    }
+
+    #[test]
+    fn eui64_swap_words() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.swap_words(),
+            Eui([0x2C, 0x3D, 0x4E, 0x5F, 0x00, 0xFF, 0x0A, 0x1B])
+        );
+        assert_eq!(eui.swap_words().swap_words(), eui);
+    }
+
+    #[test]
+    fn eui48_key() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.key(), 0xFFFF_0A1B_2C3D_4E5F);
+    }
+
+    #[test]
+    fn eui64_key() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.key(), 0x00FF_0A1B_2C3D_4E5F);
+    }
+
+    #[test]
+    fn key_distinguishes_widths() {
+        let eui48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let eui64 = Eui([0x00, 0x00, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_ne!(eui48.key(), eui64.key());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_parse_with_scope() {
+        assert_eq!(
+            EUI48::parse_with_scope("0A:1B:2C:3D:4E:5F%eth0").unwrap(),
+            (
+                Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+                Some("eth0".to_string())
+            )
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_parse_without_scope() {
+        assert_eq!(
+            EUI48::parse_with_scope("0A:1B:2C:3D:4E:5F").unwrap(),
+            (Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]), None)
+        );
+        assert_eq!(
+            EUI48::parse_with_scope("0A:1B:2C:3D:4E%eth0"),
+            Err(Error::InvalidStringLength)
+        );
+        assert_eq!(
+            EUI48::parse_with_scope("0A-1B-2C-3D-4E-5F%"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn eui48_is_valid_assignable() {
+        assert!(Eui([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]).is_valid_assignable());
+    }
+
+    #[test]
+    fn eui48_is_not_valid_assignable() {
+        // Universally administered.
+        assert!(!Eui([0x00, 0x1B, 0x44, 0x11, 0x3A, 0xB7]).is_valid_assignable());
+        // Multicast.
+        assert!(!Eui([0x03, 0x00, 0x00, 0x00, 0x00, 0x01]).is_valid_assignable());
+        // Nil.
+        assert!(!Eui([0x00, 0x00, 0x00, 0x00, 0x00, 0x00]).is_valid_assignable());
+        // Broadcast.
+        assert!(!Eui([0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).is_valid_assignable());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_to_file_key() {
+        let key = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]).to_file_key();
+        assert_eq!(key, "0a1b2c3d4e5f");
+        assert_eq!(key.len(), 12);
+        assert!(key.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')));
+    }
+
+    #[test]
+    fn eui48_group_multicast() {
+        let eui = EUI48::group_multicast([0x02, 0x1B, 0x2C], 0x3D_4E5F).unwrap();
+        assert_eq!(eui, Eui([0x03, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(eui.0[0] & 0x01, 0x01);
+    }
+
+    #[test]
+    fn eui48_group_multicast_out_of_range() {
+        assert_eq!(
+            EUI48::group_multicast([0x02, 0x1B, 0x2C], 0x100_0000),
+            Err(Error::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn eui48_to_rfc7668_iid() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.to_rfc7668_iid(),
+            [0x0A, 0x1B, 0x2C, 0xFF, 0xFE, 0x3D, 0x4E, 0x5F]
+        );
+        assert_ne!(eui.to_rfc7668_iid(), eui.modified_eui64_bytes());
+    }
+
+    #[test]
+    fn eui48_to_u64() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_u64(), 0x0A1B_2C3D_4E5F);
+    }
+
+    #[test]
+    fn eui48_from_u64() {
+        let eui = EUI48::from_u64(0x0A1B_2C3D_4E5F).unwrap();
+        assert_eq!(eui, [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(EUI48::from_u64(eui.to_u64()), Ok(eui));
+        assert_eq!(EUI48::from_u64(0xFFFF_FFFF_FFFF), Ok(EUI48::BROADCAST));
+        assert_eq!(EUI48::from_u64(0x1_0000_0000_0000), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn eui64_u64_round_trip() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_u64(), 0x00FF_0A1B_2C3D_4E5F);
+        assert_eq!(EUI64::from_u64(0x00FF_0A1B_2C3D_4E5F), eui);
+    }
+
+    #[test]
+    fn eui48_within() {
+        let a = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let b = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x02]);
+        assert!(a.within(&b, 0xA3));
+        assert!(b.within(&a, 0xA3));
+        assert!(!a.within(&b, 0xA2));
+        assert!(a.within(&a, 0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn eui48_from_os_str() {
+        let arg = std::ffi::OsString::from("0A:1B:2C:3D:4E:5F");
+        assert_eq!(
+            EUI48::try_from(arg.as_os_str()),
+            Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn eui64_from_os_str() {
+        let arg = std::ffi::OsString::from("00:FF:0A:1B:2C:3D:4E:5F");
+        assert_eq!(
+            EUI64::try_from(arg.as_os_str()),
+            Ok(Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn eui48_from_os_str_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+        let arg = std::ffi::OsStr::from_bytes(b"0A:1B:2C:3D:4E:\xFF");
+        assert_eq!(EUI48::try_from(arg), Err(Error::NonUtf8));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_table() {
+        assert_eq!(
+            parse_eui64_table("00FF0A1B2C3D4E5F-0011223344556677").unwrap(),
+            vec![
+                Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+                Eui([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_table_bad_length() {
+        assert_eq!(
+            parse_eui64_table("00FF0A1B2C3D4E5F00112233"),
+            Err(Error::InvalidStringLength)
+        );
+        assert_eq!(
+            parse_eui64_table("00FF0A1B2C3D4E5F0"),
+            Err(Error::OddLength)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_redacted_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.redacted_fmt(), "0A-1B-2C-XX-XX-XX");
     }
-}
 
-pub trait EUI {
-    fn to_bytes(&self) -> &[u8];
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_redacted_fmt_with() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.redacted_fmt_with(4), "0A-1B-XX-XX-XX-XX");
+        assert_eq!(eui.redacted_fmt_with(0), eui.to_canonical_fmt());
+    }
 
-    fn to_canonical_fmt(&self) -> String {
-        self.to_bytes().iter().fold(String::new(), |acc, new| {
-            if acc.is_empty() {
-                format!("{:02X}", new)
-            } else {
-                format!("{}-{:02X}", acc, new)
-            }
-        })
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_to_canonical_stack() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_canonical_stack().as_str(), eui.to_canonical_fmt());
+        assert_eq!(&*eui.to_canonical_stack(), "0A-1B-2C-3D-4E-5F");
     }
 
-    fn to_colon_fmt(&self) -> String {
-        self.to_bytes().iter().fold(String::new(), |acc, new| {
-            if acc.is_empty() {
-                format!("{:02X}", new)
-            } else {
-                format!("{}:{:02X}", acc, new)
-            }
-        })
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_to_canonical_stack() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_canonical_stack().as_str(), eui.to_canonical_fmt());
     }
 
-    fn to_dot_fmt(&self) -> String {
-        self.to_bytes().chunks(2).fold(String::new(), |acc, new| {
-            if acc.is_empty() {
-                format!("{:02X}{:02X}", new[0], new[1])
-            } else {
-                format!("{}.{:02X}{:02X}", acc, new[0], new[1])
-            }
-        })
+    #[test]
+    fn eui_str_is_inline() {
+        // Nothing but the formatted bytes themselves, so no heap pointer.
+        assert_eq!(std::mem::size_of::<EuiStr<17>>(), 17);
+        assert_eq!(std::mem::size_of::<EuiStr<23>>(), 23);
     }
-}
 
-impl EUI for EUI48 {
-    fn to_bytes(&self) -> &[u8] {
-        &self.0
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_compress_ranges() {
+        let addrs = [
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x60]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x61]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x00]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x01]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x50, 0x00]),
+        ];
+        assert_eq!(
+            compress_ranges(&addrs),
+            vec![
+                (
+                    Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+                    Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x61])
+                ),
+                (
+                    Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x00]),
+                    Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x01])
+                ),
+                (
+                    Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x50, 0x00]),
+                    Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x50, 0x00])
+                ),
+            ]
+        );
+        assert!(compress_ranges(&[]).is_empty());
     }
-}
 
-impl EUI for EUI64 {
-    fn to_bytes(&self) -> &[u8] {
-        &self.0
+    #[test]
+    fn eui48_eq_array() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui, [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F], eui);
+        assert_ne!(eui, [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x60]);
+        assert_ne!([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x60], eui);
     }
-}
 
-impl std::convert::TryFrom<&str> for EUI48 {
-    type Error = crate::Error;
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let s = s.replace(&['.', ':', '-'][..], "");
-        let bytes = hex::decode(s).map_err(Error::from)?;
+    #[test]
+    fn eui64_eq_array() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui, [0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F], eui);
+        assert_ne!(eui, [0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x60]);
+    }
 
-        if bytes.len() != 6 {
-            return Err(Self::Error::InvalidStringLength);
-        }
+    #[test]
+    fn eui48_from_oui_and_u24() {
+        assert_eq!(
+            EUI48::from_oui_and_u24([0x0A, 0x1B, 0x2C], 0x3D_4E5F),
+            Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+    }
 
-        let mut raw_address: [u8; 6] = Default::default();
-        raw_address.copy_from_slice(bytes.as_slice());
-        Ok(EUI48(raw_address))
+    #[test]
+    fn eui48_from_oui_and_u24_overflow() {
+        assert_eq!(
+            EUI48::from_oui_and_u24([0x0A, 0x1B, 0x2C], 0x100_0000),
+            Err(Error::OutOfRange)
+        );
     }
-}
 
-impl std::convert::TryFrom<&str> for EUI64 {
-    type Error = crate::Error;
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let s = s.replace(&['.', ':', '-'][..], "");
-        let bytes = hex::decode(s).map_err(Error::from)?;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui48_to_spaced_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_spaced_fmt(), "0A 1B 2C 3D 4E 5F");
+    }
 
-        if bytes.len() != 8 {
-            return Err(Self::Error::InvalidStringLength);
-        }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eui64_to_spaced_fmt() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_spaced_fmt(), "00 FF 0A 1B 2C 3D 4E 5F");
+    }
 
-        let mut raw_address: [u8; 8] = Default::default();
-        raw_address.copy_from_slice(bytes.as_slice());
-        Ok(EUI64(raw_address))
+    #[test]
+    fn eui48_from_spaced_fmt() {
+        assert_eq!(
+            EUI48::try_from("0a 1b 2c 3d 4e 5f").unwrap(),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{Error, EUI, EUI48, EUI64};
-    use std::convert::TryFrom;
+    #[test]
+    fn eui64_from_spaced_fmt() {
+        assert_eq!(
+            EUI64::try_from("00 ff 0a 1b 2c 3d 4e 5f").unwrap(),
+            Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+        );
+    }
 
     #[test]
-    fn eui48_to_canonical_fmt() {
-        let eui = EUI48([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
-        assert_eq!(eui.to_canonical_fmt(), "0A-1B-2C-3D-4E-5F");
+    fn canonical_width() {
+        let eui48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let eui64 = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui48.canonical_width(), 17);
+        assert_eq!(eui64.canonical_width(), 23);
+        assert_eq!(eui48.canonical_width(), eui48.to_canonical_stack().len());
+        assert_eq!(eui64.canonical_width(), eui64.to_canonical_stack().len());
     }
 
     #[test]
-    fn eui48_to_colon_fmt() {
-        let eui = EUI48([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
-        assert_eq!(eui.to_colon_fmt(), "0A:1B:2C:3D:4E:5F");
+    fn eui48_covering_prefix() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(covering_prefix(std::slice::from_ref(&eui)), Some((eui, 48)));
+        let other = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5E]);
+        assert_eq!(
+            covering_prefix(&[eui, other]),
+            Some((Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5E]), 47))
+        );
     }
 
     #[test]
-    fn eui48_to_dot_fmt() {
-        let eui = EUI48([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
-        assert_eq!(eui.to_dot_fmt(), "0A1B.2C3D.4E5F");
+    fn eui48_covering_prefix_wide() {
+        let addrs = [
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            Eui([0x0A, 0x1B, 0x2C, 0x00, 0x00, 0x00]),
+        ];
+        assert_eq!(
+            covering_prefix(&addrs),
+            Some((Eui([0x0A, 0x1B, 0x2C, 0x00, 0x00, 0x00]), 26))
+        );
+        assert_eq!(covering_prefix(&[]), None);
     }
 
     #[test]
-    fn eui64_to_canonical_fmt() {
-        let eui = EUI64([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
-        assert_eq!(eui.to_canonical_fmt(), "00-FF-0A-1B-2C-3D-4E-5F");
+    fn eui48_is_reserved_group() {
+        assert!(Eui([0x01, 0x80, 0xC2, 0x00, 0x00, 0x00]).is_reserved_group());
+        assert!(Eui([0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E]).is_reserved_group());
     }
 
     #[test]
-    fn eui64_to_colon_fmt() {
-        let eui = EUI64([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
-        assert_eq!(eui.to_colon_fmt(), "00:FF:0A:1B:2C:3D:4E:5F");
+    fn eui48_is_not_reserved_group() {
+        assert!(!Eui([0x01, 0x80, 0xC2, 0x00, 0x00, 0x10]).is_reserved_group());
+        assert!(!Eui([0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]).is_reserved_group());
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn eui64_to_dot_fmt() {
-        let eui = EUI64([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
-        assert_eq!(eui.to_dot_fmt(), "00FF.0A1B.2C3D.4E5F");
+    fn eui48_to_snmp_fmt() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_snmp_fmt(), "0a:1b:2c:3d:4e:5f");
+        assert_eq!(EUI48::from_snmp_fmt(&eui.to_snmp_fmt()), Ok(eui));
     }
 
     #[test]
-    fn eui48_from_canonical_fmt() {
+    fn eui48_remaining() {
+        let nil = Eui([0x00; 6]);
+        let broadcast = Eui([0xFF; 6]);
+        assert_eq!(nil.remaining(), 0xFFFF_FFFF_FFFF);
+        assert_eq!(broadcast.remaining(), 0);
+    }
+
+    #[test]
+    fn eui64_remaining() {
+        let nil = Eui([0x00; 8]);
+        let broadcast = Eui([0xFF; 8]);
+        assert_eq!(nil.remaining(), u64::MAX);
+        assert_eq!(broadcast.remaining(), 0);
+    }
+
+    #[test]
+    fn eui_from_str() {
         assert_eq!(
-            EUI48::try_from("0A-1B-2C-3D-4E-5F").unwrap(),
-            EUI48([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            "0A-1B-2C-3D-4E-5F".parse::<EuiAddr>(),
+            Ok(EuiAddr::EUI48(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])))
+        );
+        assert_eq!(
+            "00:FF:0A:1B:2C:3D:4E:5F".parse::<EuiAddr>(),
+            Ok(EuiAddr::EUI64(Eui([
+                0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F
+            ])))
         );
     }
 
     #[test]
-    fn eui48_from_colon_fmt() {
+    fn eui_from_str_bad_length() {
         assert_eq!(
-            EUI48::try_from("0A:1B:2C:3D:4E:5F").unwrap(),
-            EUI48([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            "0A-1B-2C-3D-4E-5F-6A".parse::<EuiAddr>(),
+            Err(Error::InvalidStringLength)
         );
     }
 
     #[test]
-    fn eui48_from_dot_fmt() {
+    fn eui48_from_loose_fmt() {
         assert_eq!(
-            EUI48::try_from("0A1B.2C3D.4E5F").unwrap(),
-            EUI48([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            EUI48::from_loose_fmt("A-1B-2C-3D-4E-5F"),
+            Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+        assert_eq!(
+            EUI48::from_loose_fmt("a:1b:2c:3d:4e:f"),
+            Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x0F]))
         );
     }
 
     #[test]
-    fn eui64_from_canonical_fmt() {
+    fn eui48_from_loose_fmt_three_digits() {
         assert_eq!(
-            EUI64::try_from("00-FF-0A-1B-2C-3D-4E-5F").unwrap(),
-            EUI64([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            EUI48::from_loose_fmt("0A1-1B-2C-3D-4E-5F"),
+            Err(Error::InvalidSeparator { c: '1', index: 2 })
+        );
+        // An even number of digits overall doesn't make the groups valid.
+        assert_eq!(
+            EUI48::from_loose_fmt("0A1-B2C-3D-4E-5F"),
+            Err(Error::InvalidSeparator { c: '1', index: 2 })
+        );
+        assert_eq!(
+            EUI48::from_loose_fmt("0A-1B-2C-3D-4E5F"),
+            Err(Error::InvalidSeparator { c: '5', index: 14 })
         );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn eui64_from_colon_fmt() {
+    fn eui48_url_fmt_round_trip() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_url_fmt(), "0a1b2c3d4e5f");
+        assert_eq!(EUI48::from_url_fmt(&eui.to_url_fmt()), Ok(eui));
+    }
+
+    #[test]
+    fn eui48_from_url_fmt_rejects_separators() {
         assert_eq!(
-            EUI64::try_from("00:FF:0A:1B:2C:3D:4E:5F").unwrap(),
-            EUI64([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            EUI48::from_url_fmt("0a:1b:2c:3d:4e5f"),
+            Err(Error::InvalidHexCharacter { c: ':', index: 2 })
         );
     }
 
     #[test]
-    fn eui64_from_dot_fmt() {
+    fn eui48_complement() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.complement(), Eui([0xF5, 0xE4, 0xD3, 0xC2, 0xB1, 0xA0]));
+        assert_eq!(eui.complement().complement(), eui);
+        assert_eq!(Eui([0x00; 6]).complement(), Eui([0xFF; 6]));
+    }
+
+    #[test]
+    fn eui64_complement() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
         assert_eq!(
-            EUI64::try_from("00FF.0A1B.2C3D.4E5F").unwrap(),
-            EUI64([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            eui.complement(),
+            Eui([0xFF, 0x00, 0xF5, 0xE4, 0xD3, 0xC2, 0xB1, 0xA0])
         );
+        assert_eq!(eui.complement().complement(), eui);
+        assert_eq!(Eui([0x00; 8]).complement(), Eui([0xFF; 8]));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn eui48_from_canonical_fmt_bad_character() {
+    fn eui48_display() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(format!("{}", eui), "0A-1B-2C-3D-4E-5F");
+        assert_eq!(eui.to_string(), eui.to_canonical_fmt());
+    }
+
+    #[test]
+    fn eui64_display() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(format!("{}", eui), "00-FF-0A-1B-2C-3D-4E-5F");
+    }
+
+    #[test]
+    fn eui48_from_str() {
         assert_eq!(
-            EUI48::try_from("0A-1B-2C-3D-4x-5F"),
-            Err(Error::InvalidHexCharacter)
+            "0A:1B:2C:3D:4E:5F".parse::<EUI48>(),
+            Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+        assert_eq!(
+            "0A:1B:2C:3D:4E".parse::<EUI48>(),
+            Err(Error::InvalidStringLength)
         );
     }
 
     #[test]
-    fn eui48_from_canonical_fmt_missing_byte() {
+    fn eui64_from_str() {
         assert_eq!(
-            EUI48::try_from("0A-1B-2C-3D-4E"),
-            Err(Error::InvalidStringLength)
+            "00FF.0A1B.2C3D.4E5F".parse::<EUI64>(),
+            Ok(Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
         );
     }
 
     #[test]
-    fn eui48_from_canonical_fmt_missing_character() {
-        assert_eq!(EUI48::try_from("0A-1B-2C-3D-4E-5"), Err(Error::OddLength));
+    fn eui48_ord_is_numeric() {
+        let mut addrs = [
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4F, 0x00]),
+            Eui([0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+        ];
+        addrs.sort();
+        assert!(addrs.windows(2).all(|w| w[0].to_u64() < w[1].to_u64()));
     }
 
     #[test]
-    fn eui64_from_canonical_fmt_bad_character() {
+    fn eui48_hash_set() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let set: std::collections::HashSet<EUI48> = vec![eui, eui].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&eui));
+    }
+
+    #[test]
+    fn eui64_ord_is_numeric() {
+        let low = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let high = Eui([0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert!(low < high);
         assert_eq!(
-            EUI64::try_from("00-FF-0A-1B-2C-3D-4x-5F"),
-            Err(Error::InvalidHexCharacter)
+            std::collections::BTreeSet::from([high, low])
+                .into_iter()
+                .next(),
+            Some(low)
         );
     }
 
     #[test]
-    fn eui64_from_canonical_fmt_missing_byte() {
+    fn eui48_const() {
+        const EUI: EUI48 = EUI48::new([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        const OCTETS: [u8; 6] = EUI.octets();
+        static TABLE: [EUI48; 2] = [EUI, EUI48::new([0x00; 6])];
+        assert_eq!(EUI, Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(OCTETS, [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(TABLE[0], EUI);
+    }
+
+    #[test]
+    fn eui64_const() {
+        const EUI: EUI64 = EUI64::new([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        const OCTETS: [u8; 8] = EUI.octets();
+        assert_eq!(EUI, Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(OCTETS, [0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+    }
+
+    #[test]
+    fn eui48_macro() {
+        const EUI: EUI48 = eui48!("0a:1b:2c:3d:4e:5f");
+        assert_eq!(EUI, Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+    }
+
+    #[test]
+    fn eui64_macro() {
         assert_eq!(
-            EUI64::try_from("00-FF-0A-1B-2C-3D-4E"),
-            Err(Error::InvalidStringLength)
+            eui64!("00FF.0A1B.2C3D.4E5F"),
+            Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
         );
     }
 
     #[test]
-    fn eui64_from_canonical_fmt_missing_character() {
+    fn parse_const_errors() {
         assert_eq!(
-            EUI64::try_from("00-FF-0A-1B-2C-3D-4E-5"),
+            EUI48::parse_const("0A-1B-2C-3D-4x-5F"),
+            Err(Error::InvalidHexCharacter { c: 'x', index: 13 })
+        );
+        assert_eq!(
+            EUI48::parse_const("0A-1B-2C-3D-4E"),
+            Err(Error::InvalidStringLength)
+        );
+        assert_eq!(
+            EUI64::parse_const("00-FF-0A-1B-2C-3D-4E-5"),
             Err(Error::OddLength)
         );
     }
+
+    #[test]
+    fn eui48_to_eui64() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.to_eui64(),
+            Eui([0x0A, 0x1B, 0x2C, 0xFF, 0xFE, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui48_to_modified_eui64() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(
+            eui.to_modified_eui64(),
+            Eui([0x08, 0x1B, 0x2C, 0xFF, 0xFE, 0x3D, 0x4E, 0x5F])
+        );
+    }
+
+    #[test]
+    fn eui64_to_eui48() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_eui64().to_eui48(), Some(eui));
+        assert_eq!(
+            Eui([0x0A, 0x1B, 0x2C, 0xFF, 0xFF, 0x3D, 0x4E, 0x5F]).to_eui48(),
+            Some(eui)
+        );
+    }
+
+    #[test]
+    fn eui64_to_eui48_not_encapsulated() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_eui48(), None);
+    }
+
+    #[test]
+    fn eui48_bit_flags() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert!(eui.is_unicast() && !eui.is_multicast());
+        assert!(eui.is_locally_administered() && !eui.is_universally_administered());
+
+        let eui = Eui([0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]);
+        assert!(eui.is_multicast() && !eui.is_unicast());
+        assert!(eui.is_universally_administered() && !eui.is_locally_administered());
+    }
+
+    #[test]
+    fn eui64_bit_flags() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert!(eui.is_unicast() && eui.is_universally_administered());
+
+        let eui = Eui([0x03, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert!(eui.is_multicast() && eui.is_locally_administered());
+    }
+
+    #[test]
+    fn eui48_with_bits() {
+        let base = Eui([0x00, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let local = base.with_local_bit(true);
+        assert_eq!(local, [0x02, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(local.with_local_bit(false), base);
+        let group = base.with_multicast_bit(true);
+        assert_eq!(group, [0x01, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(group.with_multicast_bit(false), base);
+        assert_eq!(local.with_local_bit(true), local);
+    }
+
+    #[test]
+    fn eui48_set_bits() {
+        let mut eui = Eui([0x00, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        eui.set_local_bit(true);
+        eui.set_multicast_bit(true);
+        assert_eq!(eui, [0x03, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        eui.set_local_bit(false);
+        assert_eq!(eui, [0x01, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+    }
+
+    #[test]
+    fn eui64_with_bits() {
+        let mut eui = Eui([0xFF; 8]).with_local_bit(false);
+        assert_eq!(eui, [0xFD, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        eui.set_multicast_bit(false);
+        assert_eq!(eui, [0xFC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn eui48_broadcast_nil() {
+        assert!(EUI48::BROADCAST.is_broadcast() && !EUI48::BROADCAST.is_nil());
+        assert!(EUI48::NIL.is_nil() && !EUI48::NIL.is_broadcast());
+        assert_eq!(EUI48::default(), EUI48::NIL);
+        assert_eq!(EUI48::BROADCAST.to_string(), "FF-FF-FF-FF-FF-FF");
+        let eui = Eui([0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+        assert!(!eui.is_broadcast() && !eui.is_nil());
+    }
+
+    #[test]
+    fn eui64_broadcast_nil() {
+        assert!(EUI64::BROADCAST.is_broadcast() && !EUI64::BROADCAST.is_nil());
+        assert!(EUI64::NIL.is_nil() && !EUI64::NIL.is_broadcast());
+        assert_eq!(EUI64::default(), EUI64::NIL);
+        let eui = Eui([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        assert!(!eui.is_broadcast() && !eui.is_nil());
+    }
+
+    #[test]
+    fn eui48_endianness() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_be_bytes(), [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_le_bytes(), [0x5F, 0x4E, 0x3D, 0x2C, 0x1B, 0x0A]);
+        assert_eq!(EUI48::from_be_bytes(eui.to_be_bytes()), eui);
+        assert_eq!(EUI48::from_le_bytes(eui.to_le_bytes()), eui);
+    }
+
+    #[test]
+    fn eui64_endianness() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.to_le_bytes(), eui.to_u64().to_le_bytes());
+        assert_eq!(EUI64::from_le_bytes(eui.to_le_bytes()), eui);
+        assert_eq!(EUI64::from_be_bytes(eui.to_be_bytes()), eui);
+    }
+
+    #[test]
+    fn eui48_array_conversions() {
+        let bytes = [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F];
+        let eui = EUI48::from(bytes);
+        assert_eq!(eui.into_array(), bytes);
+        assert_eq!(<[u8; 6]>::from(eui), bytes);
+        assert_eq!(eui.as_ref(), &bytes[..]);
+    }
+
+    #[test]
+    fn eui64_array_conversions() {
+        let bytes = [0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F];
+        let eui: EUI64 = bytes.into();
+        assert_eq!(eui.into_array(), bytes);
+        assert_eq!(eui.as_ref(), &bytes[..]);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let frame = [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F,
+        ];
+        assert_eq!(EUI48::try_from(&frame[..6]), Ok(EUI48::BROADCAST));
+        assert_eq!(
+            EUI48::try_from(&frame[6..]),
+            Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+        assert_eq!(EUI48::try_from(&frame[..5]), Err(Error::InvalidSliceLength));
+        assert_eq!(
+            EUI64::try_from(&frame[..8]).map(|e| e.to_u64()),
+            Ok(0xFFFF_FFFF_FFFF_0A1B)
+        );
+        assert_eq!(EUI64::try_from(&frame[..]), Err(Error::InvalidSliceLength));
+    }
+
+    #[test]
+    fn from_ref() {
+        let frame = [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F,
+        ];
+        let dst = EUI48::from_slice(&frame[..6]).unwrap();
+        assert!(dst.is_broadcast());
+        assert_eq!(
+            EUI48::from_slice(&frame[6..]),
+            Ok(&Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+        assert_eq!(dst.as_ref().as_ptr(), frame.as_ptr());
+        assert_eq!(
+            EUI48::from_slice(&frame[..5]),
+            Err(Error::InvalidSliceLength)
+        );
+        assert_eq!(EUI64::from_slice(&frame), Err(Error::InvalidSliceLength));
+
+        const BYTES: [u8; 8] = [0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F];
+        const EUI: &EUI64 = EUI64::from_ref(&BYTES);
+        assert_eq!(EUI.to_u64(), 0x00FF_0A1B_2C3D_4E5F);
+    }
+
+    #[test]
+    fn eui48_from_loose_fmt_errors() {
+        assert_eq!(
+            EUI48::from_loose_fmt("A-1B-2C-3D-4E"),
+            Err(Error::InvalidStringLength)
+        );
+        assert_eq!(
+            EUI48::from_loose_fmt("A-1B-2C-3D-4E-5F-60"),
+            Err(Error::InvalidStringLength)
+        );
+        assert_eq!(
+            EUI48::from_loose_fmt("G-1B-2C-3D-4E-5F"),
+            Err(Error::InvalidHexCharacter { c: 'G', index: 0 })
+        );
+    }
+
+    #[test]
+    fn write_formats() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let mut s = String::new();
+        eui.write_canonical(&mut s).unwrap();
+        s.push(' ');
+        eui.write_colon(&mut s).unwrap();
+        s.push(' ');
+        Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])
+            .write_dot(&mut s)
+            .unwrap();
+        assert_eq!(s, "0A-1B-2C-3D-4E-5F 0A:1B:2C:3D:4E:5F 00FF.0A1B.2C3D.4E5F");
+    }
+
+    #[test]
+    fn format_into() {
+        let mut buf = [0u8; 32];
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.format_into(&mut buf), "00-FF-0A-1B-2C-3D-4E-5F");
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.format_into(&mut buf[..17]), "0A-1B-2C-3D-4E-5F");
+    }
+
+    #[test]
+    #[should_panic]
+    fn format_into_short_buffer() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        eui.format_into(&mut [0u8; 16]);
+    }
+
+    #[test]
+    fn display_flags() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(format!("{}", eui), "0A-1B-2C-3D-4E-5F");
+        assert_eq!(format!("{:#}", eui), "0A:1B:2C:3D:4E:5F");
+        assert_eq!(format!("[{:20}]", eui), "[0A-1B-2C-3D-4E-5F   ]");
+        assert_eq!(format!("[{:>20}]", eui), "[   0A-1B-2C-3D-4E-5F]");
+        assert_eq!(format!("[{:*^#21}]", eui), "[**0A:1B:2C:3D:4E:5F**]");
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(format!("{:#}", eui), "00:FF:0A:1B:2C:3D:4E:5F");
+        assert_eq!(format!("{:.5}", eui), "00-FF");
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy_ethernet_header() {
+        use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+        #[derive(FromBytes, IntoBytes, Unaligned, Immutable, KnownLayout)]
+        #[repr(C)]
+        struct EthernetHeader {
+            dst: EUI48,
+            src: EUI48,
+            ether_type: [u8; 2],
+        }
+
+        let frame = [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F, 0x08, 0x06,
+            0x00, 0x01,
+        ];
+        let (header, payload) = EthernetHeader::ref_from_prefix(&frame).unwrap();
+        assert!(header.dst.is_broadcast());
+        assert_eq!(header.src, Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(header.ether_type, [0x08, 0x06]);
+        assert_eq!(payload, [0x00, 0x01]);
+        assert_eq!(header.src.as_bytes(), &frame[6..12]);
+        assert!(EUI64::ref_from_bytes(&frame[..6]).is_err());
+    }
 }
@@ -0,0 +1,141 @@
+//! LoRaWAN device and join EUIs.
+//!
+//! LoRaWAN frames carry EUIs least significant byte first, and many stacks
+//! and chip vendors exchange them as hex in that order too, while labels and
+//! network servers show them most significant byte first. The types here
+//! keep the two apart.
+
+use crate::{Error, Eui, EUI, EUI64};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+impl EUI64 {
+    /// Parses hex whose bytes are least significant first, e.g.
+    /// `5F4E3D2C1B0AFF00` for `00-FF-0A-1B-2C-3D-4E-5F`.
+    pub fn from_lsb_hex(s: &str) -> Result<EUI64, Error> {
+        EUI64::parse_const(s).map(|eui| EUI64::from_le_bytes(eui.0))
+    }
+
+    /// Writes the address as bare hex, least significant byte first.
+    pub fn write_lsb_hex(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        Eui(self.to_le_bytes()).write_bare(w)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_lsb_hex(&self) -> String {
+        Eui(self.to_le_bytes()).to_bare_fmt()
+    }
+}
+
+macro_rules! lorawan_eui {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        ///
+        /// `Display` and `FromStr` use the most significant byte first order
+        /// of labels; use the `lsb_hex` methods for the reverse.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(EUI64);
+
+        impl $name {
+            pub const fn new(eui: EUI64) -> $name {
+                $name(eui)
+            }
+
+            pub const fn eui64(&self) -> EUI64 {
+                self.0
+            }
+
+            pub fn from_lsb_hex(s: &str) -> Result<$name, Error> {
+                EUI64::from_lsb_hex(s).map($name)
+            }
+
+            pub fn write_lsb_hex(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+                self.0.write_lsb_hex(w)
+            }
+
+            #[cfg(feature = "alloc")]
+            pub fn to_lsb_hex(&self) -> String {
+                self.0.to_lsb_hex()
+            }
+        }
+
+        impl From<EUI64> for $name {
+            fn from(eui: EUI64) -> Self {
+                $name(eui)
+            }
+        }
+
+        impl From<$name> for EUI64 {
+            fn from(eui: $name) -> Self {
+                eui.0
+            }
+        }
+
+        impl core::str::FromStr for $name {
+            type Err = crate::Error;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                EUI64::parse_const(s).map($name)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.write_bare(f)
+            }
+        }
+    };
+}
+
+lorawan_eui!(
+    /// The DevEUI identifying an end device.
+    DevEui
+);
+
+lorawan_eui!(
+    /// The JoinEUI (AppEUI before LoRaWAN 1.1) identifying a join server.
+    JoinEui
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{DevEui, JoinEui};
+    use crate::{Error, Eui, EUI64};
+
+    const EUI: EUI64 = Eui([0x70, 0xB3, 0xD5, 0x7E, 0xD0, 0x00, 0x12, 0x34]);
+
+    #[test]
+    fn lsb_hex() {
+        assert_eq!(EUI64::from_lsb_hex("341200D07ED5B370"), Ok(EUI));
+        let mut s = String::new();
+        EUI.write_lsb_hex(&mut s).unwrap();
+        assert_eq!(s, "341200D07ED5B370");
+        assert_eq!(
+            EUI64::from_lsb_hex("341200D07ED5B3"),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn dev_eui() {
+        let dev_eui: DevEui = "70B3D57ED0001234".parse().unwrap();
+        assert_eq!(dev_eui.eui64(), EUI);
+        assert_eq!(dev_eui.to_string(), "70B3D57ED0001234");
+        assert_eq!(DevEui::from_lsb_hex("341200D07ED5B370"), Ok(dev_eui));
+    }
+
+    #[test]
+    fn join_eui() {
+        let join_eui = JoinEui::from(EUI);
+        assert_eq!(EUI64::from(join_eui), EUI);
+        let mut s = String::new();
+        join_eui.write_lsb_hex(&mut s).unwrap();
+        assert_eq!(s, "341200D07ED5B370");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_lsb_hex() {
+        assert_eq!(EUI.to_lsb_hex(), "341200D07ED5B370");
+        assert_eq!(DevEui::new(EUI).to_lsb_hex(), "341200D07ED5B370");
+    }
+}
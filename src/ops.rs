@@ -0,0 +1,106 @@
+//! Bitwise operators, for masking and comparing addresses directly.
+
+use crate::{Eui, Width};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+macro_rules! bitwise {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+        impl<const N: usize> $assign<[u8; N]> for Eui<N>
+        where
+            [u8; N]: Width,
+        {
+            fn $assign_method(&mut self, rhs: [u8; N]) {
+                self.0
+                    .iter_mut()
+                    .zip(rhs.iter())
+                    .for_each(|(a, b)| a.$assign_method(b));
+            }
+        }
+
+        impl<const N: usize> $assign for Eui<N>
+        where
+            [u8; N]: Width,
+        {
+            fn $assign_method(&mut self, rhs: Eui<N>) {
+                self.$assign_method(rhs.0);
+            }
+        }
+
+        impl<const N: usize> $op<[u8; N]> for Eui<N>
+        where
+            [u8; N]: Width,
+        {
+            type Output = Eui<N>;
+            fn $method(mut self, rhs: [u8; N]) -> Eui<N> {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<const N: usize> $op for Eui<N>
+        where
+            [u8; N]: Width,
+        {
+            type Output = Eui<N>;
+            fn $method(self, rhs: Eui<N>) -> Eui<N> {
+                self.$method(rhs.0)
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<const N: usize> Not for Eui<N>
+where
+    [u8; N]: Width,
+{
+    type Output = Eui<N>;
+    fn not(self) -> Eui<N> {
+        self ^ [0xFF; N]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EUI48, EUI64};
+
+    const EUI: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+
+    #[test]
+    fn eui48() {
+        let mask = Eui([0xFF, 0xFF, 0xFF, 0, 0, 0]);
+        assert_eq!(EUI & mask, Eui([0x0A, 0x1B, 0x2C, 0, 0, 0]));
+        assert_eq!(EUI | mask, Eui([0xFF, 0xFF, 0xFF, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(EUI ^ EUI, EUI48::NIL);
+        assert_eq!(!EUI, EUI.complement());
+        assert_eq!(!EUI48::NIL, EUI48::BROADCAST);
+    }
+
+    #[test]
+    fn raw_arrays() {
+        assert_eq!(EUI & [0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], EUI);
+        assert_eq!(EUI | [0x01, 0, 0, 0, 0, 0], EUI.with_multicast_bit(true));
+        assert_eq!(EUI ^ [0x02, 0, 0, 0, 0, 0], EUI.with_local_bit(false));
+    }
+
+    #[test]
+    fn assign() {
+        let mut eui = EUI;
+        eui &= [0xFF, 0xFF, 0xFF, 0, 0, 0];
+        eui |= Eui([0, 0, 0, 0, 0, 0x01]);
+        eui ^= [0, 0, 0, 0, 0, 0x03];
+        assert_eq!(eui, Eui([0x0A, 0x1B, 0x2C, 0, 0, 0x02]));
+    }
+
+    #[test]
+    fn eui64() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(!eui, eui.complement());
+        assert_eq!(eui & EUI64::NIL, EUI64::NIL);
+        assert_eq!(eui | EUI64::BROADCAST, EUI64::BROADCAST);
+        assert_eq!(eui ^ [0xFF; 8], !eui);
+    }
+}
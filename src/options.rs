@@ -0,0 +1,286 @@
+//! Configurable parsing, for inputs that need more or less tolerance than
+//! `TryFrom<&str>` offers.
+
+use crate::{parser, Case, Error, Format};
+
+/// How lenient [`EUI48::parse_with`](crate::EUI48::parse_with) and
+/// [`EUI64::parse_with`](crate::EUI64::parse_with) are. The default behaves
+/// like `TryFrom<&str>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    trim: bool,
+    extract: bool,
+    hex_prefix: bool,
+    separators: &'static str,
+    case: Option<Case>,
+    format: Option<Format>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            trim: false,
+            extract: false,
+            hex_prefix: false,
+            separators: parser::SEPARATORS,
+            case: None,
+            format: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        ParseOptions::default()
+    }
+
+    /// Ignores leading and trailing whitespace.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Looks for the address among whitespace-separated text, skipping
+    /// labels before it and descriptions after it, as in `ipconfig /all`'s
+    /// `Physical Address. . . : 0A-1B-2C-3D-4E-5F`. Implies trimming.
+    pub fn extract(mut self, extract: bool) -> Self {
+        self.extract = extract;
+        self
+    }
+
+    /// Accepts a leading `0x` or `0X`.
+    pub fn hex_prefix(mut self, hex_prefix: bool) -> Self {
+        self.hex_prefix = hex_prefix;
+        self
+    }
+
+    /// Sets the ASCII characters that may appear, anywhere, between digits.
+    /// Ignored if a [`Format`] is required.
+    pub fn separators(mut self, separators: &'static str) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Requires the hex digits A to F to be in `case`.
+    pub fn case(mut self, case: Case) -> Self {
+        self.case = Some(case);
+        self
+    }
+
+    /// Requires the layout of `format`, separators included.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub(crate) fn decode<const N: usize>(&self, s: &str) -> Result<[u8; N], Error> {
+        if self.extract {
+            if let Some(bytes) = self.find(s) {
+                return Ok(bytes);
+            }
+        }
+
+        let mut offset = 0;
+        let mut s = s;
+        if self.trim || self.extract {
+            offset = s.len() - s.trim_start().len();
+            s = s.trim();
+        }
+        if self.hex_prefix && (s.starts_with("0x") || s.starts_with("0X")) {
+            offset += 2;
+            s = &s[2..];
+        }
+
+        self.decode_trimmed(s).map_err(|e| match e {
+            Error::InvalidHexCharacter { c, index } => Error::InvalidHexCharacter {
+                c,
+                index: index + offset,
+            },
+            Error::InvalidSeparator { c, index } => Error::InvalidSeparator {
+                c,
+                index: index + offset,
+            },
+            e => e,
+        })
+    }
+
+    /// Decodes the first run of whitespace-separated tokens in `s` that
+    /// holds exactly the digits of one address.
+    fn find<const N: usize>(&self, s: &str) -> Option<[u8; N]> {
+        let tokens = || s.split_ascii_whitespace();
+        let is_digit = |b: &u8| b.is_ascii_hexdigit();
+        let is_part = |token: &str| {
+            token.bytes().all(|b| {
+                b.is_ascii_hexdigit()
+                    || parser::is_separator(b)
+                    || self.separators.as_bytes().contains(&b)
+            })
+        };
+        // Tokens are subslices of `s`, so their offsets can be recovered.
+        let offset = |token: &str| token.as_ptr() as usize - s.as_ptr() as usize;
+
+        for (i, first) in tokens().enumerate() {
+            if !first.bytes().any(|b| is_digit(&b)) {
+                continue;
+            }
+            let mut digits = 0;
+            for token in tokens().skip(i).take_while(|token| is_part(token)) {
+                digits += token.bytes().filter(is_digit).count();
+                if digits == 2 * N {
+                    let span = &s[offset(first)..offset(token) + token.len()];
+                    if let Ok(bytes) = self.decode_trimmed(span) {
+                        return Some(bytes);
+                    }
+                }
+                if digits >= 2 * N {
+                    break;
+                }
+            }
+        }
+        None
+    }
+
+    fn decode_trimmed<const N: usize>(&self, s: &str) -> Result<[u8; N], Error> {
+        let separators = match self.format {
+            Some(format) => {
+                format.check(s, N)?;
+                parser::SEPARATORS
+            }
+            None => self.separators,
+        };
+        let is_separator = |b: u8| separators.as_bytes().contains(&b);
+
+        let len = s.bytes().filter(|b| !is_separator(*b)).count();
+        if len % 2 != 0 {
+            return Err(Error::OddLength);
+        }
+        for (i, b) in s.bytes().enumerate() {
+            let valid = match self.case {
+                _ if is_separator(b) => true,
+                Some(Case::Upper) => matches!(b, b'0'..=b'9' | b'A'..=b'F'),
+                Some(Case::Lower) => matches!(b, b'0'..=b'9' | b'a'..=b'f'),
+                None => b.is_ascii_hexdigit(),
+            };
+            if !valid {
+                return Err(Error::InvalidHexCharacter {
+                    c: parser::char_at(s.as_bytes(), i),
+                    index: i,
+                });
+            }
+        }
+        if len != 2 * N {
+            return Err(Error::InvalidStringLength);
+        }
+
+        let mut out = [0u8; N];
+        for (i, b) in s.bytes().filter(|b| !is_separator(*b)).enumerate() {
+            out[i / 2] |= parser::hex_value(b) << (4 * (1 - i % 2));
+        }
+        Ok(out)
+    }
+}
+
+impl From<Format> for ParseOptions {
+    fn from(format: Format) -> Self {
+        ParseOptions::new().format(format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseOptions;
+    use crate::{Case, Error, Eui, Format, EUI48, EUI64};
+
+    const EUI: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+
+    #[test]
+    fn default_matches_try_from() {
+        let options = ParseOptions::new();
+        for s in [
+            "0A-1B-2C-3D-4E-5F",
+            "0A:1B-2C.3D4E5F",
+            "0A-1B-2C-3D-4x-5F",
+            "0A-1B",
+        ] {
+            assert_eq!(EUI48::parse_with(s, options), s.parse::<EUI48>());
+        }
+    }
+
+    #[test]
+    fn trim_and_hex_prefix() {
+        let options = ParseOptions::new().trim(true).hex_prefix(true);
+        assert_eq!(EUI48::parse_with(" 0x0A1B2C3D4E5F\n", options), Ok(EUI));
+        assert_eq!(EUI48::parse_with("0A1B2C3D4E5F", options), Ok(EUI));
+        assert_eq!(
+            EUI48::parse_with(" 0x0A1B2C3D4E5G", options),
+            Err(Error::InvalidHexCharacter { c: 'G', index: 14 })
+        );
+        assert_eq!(
+            EUI48::parse_with("0x0A1B2C3D4E5F", ParseOptions::new()),
+            Err(Error::InvalidHexCharacter { c: 'x', index: 1 })
+        );
+    }
+
+    #[test]
+    fn separators() {
+        let options = ParseOptions::new().separators("_");
+        assert_eq!(EUI48::parse_with("0A_1B_2C_3D_4E_5F", options), Ok(EUI));
+        assert_eq!(
+            EUI48::parse_with("0A:1B:2C:3D:4E5F", options),
+            Err(Error::InvalidHexCharacter { c: ':', index: 2 })
+        );
+        let options = ParseOptions::new().separators("");
+        assert_eq!(EUI48::parse_with("0a1b2c3d4e5f", options), Ok(EUI));
+    }
+
+    #[test]
+    fn case() {
+        let options = ParseOptions::new().case(Case::Lower);
+        assert_eq!(EUI48::parse_with("0a:1b:2c:3d:4e:5f", options), Ok(EUI));
+        assert_eq!(
+            EUI48::parse_with("0a:1b:2c:3d:4E:5f", options),
+            Err(Error::InvalidHexCharacter { c: 'E', index: 13 })
+        );
+    }
+
+    #[test]
+    fn format() {
+        let options = ParseOptions::new().format(Format::Dot).case(Case::Upper);
+        assert_eq!(
+            EUI64::parse_with("00FF.0A1B.2C3D.4E5F", options),
+            Ok(Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]))
+        );
+        assert_eq!(
+            EUI64::parse_with("00FF:0A1B:2C3D:4E5F", options),
+            Err(Error::InvalidSeparator { c: ':', index: 4 })
+        );
+    }
+
+    #[test]
+    fn extract() {
+        let options = ParseOptions::new().extract(true);
+        let inputs = [
+            "   Physical Address. . . . . . . . . : 0A-1B-2C-3D-4E-5F",
+            "0A 1B 2C 3D 4E 5F  Intel(R) Ethernet Connection",
+            "ether 0a:1b:2c:3d:4e:5f  txqueuelen 1000",
+            "Bad 0A 1B 2C 3D 4E 5F",
+            "\t0A1B.2C3D.4E5F\r\n",
+        ];
+        for input in inputs {
+            assert_eq!(EUI48::parse_with(input, options), Ok(EUI), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn extract_not_found() {
+        let options = ParseOptions::new().extract(true);
+        assert_eq!(
+            EUI48::parse_with("Physical Address: none", options),
+            Err(Error::OddLength)
+        );
+        assert_eq!(
+            EUI48::parse_with(" 0A-1B-2C-3D-4E ", options),
+            Err(Error::InvalidStringLength)
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! How an address was most likely assigned.
+
+use crate::EUI48;
+
+// Locally administered prefixes handed out deterministically by common
+// virtualization stacks, which would otherwise look randomized.
+const KNOWN_LOCAL_PREFIXES: &[&[u8]] = &[
+    &[0x02, 0x42],       // Docker bridge networks
+    &[0x52, 0x54, 0x00], // QEMU/KVM
+    &[0x0A, 0x58, 0x0A], // OVN-Kubernetes pods
+];
+
+/// The likely origin of a unicast or multicast address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressOrigin {
+    /// Assigned by a manufacturer under an IEEE-registered OUI.
+    Global,
+    /// Locally administered under a prefix reserved by a known scheme, e.g.
+    /// a hypervisor.
+    Local,
+    /// Locally administered with no recognizable structure, as generated by
+    /// the MAC privacy features of iOS, Android and Windows.
+    Randomized,
+    /// A group address, which no device uses as its source.
+    Multicast,
+}
+
+impl EUI48 {
+    pub fn origin(&self) -> AddressOrigin {
+        if self.is_multicast() {
+            AddressOrigin::Multicast
+        } else if self.is_universally_administered() {
+            AddressOrigin::Global
+        } else if KNOWN_LOCAL_PREFIXES.iter().any(|p| self.0.starts_with(p)) {
+            AddressOrigin::Local
+        } else {
+            AddressOrigin::Randomized
+        }
+    }
+
+    /// Returns whether the address looks like a per-network or per-session
+    /// privacy address rather than a device's burned-in one. This is a
+    /// heuristic: the privacy schemes randomize every SLAP quadrant, so
+    /// structured local addresses such as ELIs are reported as randomized
+    /// too.
+    pub fn is_randomized(&self) -> bool {
+        self.origin() == AddressOrigin::Randomized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AddressOrigin, Eui};
+
+    #[test]
+    fn origin() {
+        let origin = |bytes| Eui(bytes).origin();
+        assert_eq!(
+            origin([0x00, 0x50, 0x56, 0x12, 0x34, 0x56]),
+            AddressOrigin::Global
+        );
+        assert_eq!(
+            origin([0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]),
+            AddressOrigin::Multicast
+        );
+        assert_eq!(
+            origin([0x02, 0x42, 0xAC, 0x11, 0x00, 0x02]),
+            AddressOrigin::Local
+        );
+        assert_eq!(
+            origin([0x52, 0x54, 0x00, 0x12, 0x34, 0x56]),
+            AddressOrigin::Local
+        );
+        assert_eq!(
+            origin([0x1A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            AddressOrigin::Randomized
+        );
+        assert_eq!(
+            origin([0xDA, 0xA1, 0x19, 0x6B, 0x0C, 0x2E]),
+            AddressOrigin::Randomized
+        );
+    }
+
+    #[test]
+    fn is_randomized() {
+        assert!(Eui([0x3E, 0x22, 0xFB, 0x97, 0x10, 0x6D]).is_randomized());
+        assert!(Eui([0x76, 0x9A, 0x01, 0x3C, 0x55, 0x80]).is_randomized());
+        assert!(!Eui([0xB8, 0x27, 0xEB, 0x12, 0x34, 0x56]).is_randomized());
+        assert!(!Eui([0x02, 0x42, 0xAC, 0x11, 0x00, 0x02]).is_randomized());
+    }
+}
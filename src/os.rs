@@ -0,0 +1,201 @@
+//! Hardware addresses of local network interfaces.
+
+use crate::EUI48;
+use std::io;
+
+impl EUI48 {
+    /// Returns the hardware address of the network interface `name`, e.g.
+    /// `eth0` on Linux, `en0` on macOS, or `Ethernet` on Windows, where the
+    /// adapter's GUID is accepted too.
+    ///
+    /// Fails with `NotFound` if there is no such interface or it has no
+    /// EUI-48, and with `Unsupported` on other platforms.
+    pub fn from_interface(name: &str) -> io::Result<EUI48> {
+        imp::from_interface(name)
+    }
+}
+
+fn not_found() -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, "no such interface with an EUI-48")
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod imp {
+    use crate::EUI48;
+    use core::convert::TryFrom;
+    use std::io;
+    use std::path::Path;
+
+    pub(super) fn from_interface(name: &str) -> io::Result<EUI48> {
+        if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid interface name",
+            ));
+        }
+        let path = Path::new("/sys/class/net").join(name).join("address");
+        let address = std::fs::read_to_string(path)?;
+        // Interfaces of other link layers, e.g. InfiniBand, have longer
+        // addresses; point-to-point ones have none.
+        EUI48::try_from(address.trim()).map_err(|_| super::not_found())
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+mod imp {
+    use crate::{Eui, EUI48};
+    use std::ffi::CStr;
+    use std::{io, ptr};
+
+    pub(super) fn from_interface(name: &str) -> io::Result<EUI48> {
+        let mut addrs = ptr::null_mut();
+        // SAFETY: on success, `addrs` is a list we own until freed below.
+        if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut result = Err(super::not_found());
+        let mut cursor = addrs;
+        while !cursor.is_null() {
+            // SAFETY: the entries, their names and addresses are valid until
+            // `freeifaddrs`; link-layer addresses are `sockaddr_dl`s, whose
+            // data holds the name and then the `sdl_alen` address octets.
+            unsafe {
+                let ifa = &*cursor;
+                cursor = ifa.ifa_next;
+                if ifa.ifa_addr.is_null()
+                    || i32::from((*ifa.ifa_addr).sa_family) != libc::AF_LINK
+                    || CStr::from_ptr(ifa.ifa_name).to_bytes() != name.as_bytes()
+                {
+                    continue;
+                }
+                let sdl = ifa.ifa_addr as *const libc::sockaddr_dl;
+                if (*sdl).sdl_alen == 6 {
+                    let data = ptr::addr_of!((*sdl).sdl_data) as *const u8;
+                    let mut octets = [0u8; 6];
+                    ptr::copy_nonoverlapping(
+                        data.add(usize::from((*sdl).sdl_nlen)),
+                        octets.as_mut_ptr(),
+                        6,
+                    );
+                    result = Ok(Eui(octets));
+                }
+                break;
+            }
+        }
+
+        // SAFETY: `addrs` came from `getifaddrs` and is freed once.
+        unsafe { libc::freeifaddrs(addrs) };
+        result
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use crate::{Eui, EUI48};
+    use std::ffi::CStr;
+    use std::{io, ptr};
+    use windows_sys::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, NO_ERROR};
+    use windows_sys::Win32::NetworkManagement::IpHelper::{
+        GetAdaptersAddresses, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER,
+        GAA_FLAG_SKIP_MULTICAST, GAA_FLAG_SKIP_UNICAST, IP_ADAPTER_ADDRESSES_LH,
+    };
+    use windows_sys::Win32::Networking::WinSock::AF_UNSPEC;
+
+    pub(super) fn from_interface(name: &str) -> io::Result<EUI48> {
+        let flags = GAA_FLAG_SKIP_UNICAST
+            | GAA_FLAG_SKIP_ANYCAST
+            | GAA_FLAG_SKIP_MULTICAST
+            | GAA_FLAG_SKIP_DNS_SERVER;
+        let mut size = 16 * 1024;
+        // Backed by `u64`s for the alignment of `IP_ADAPTER_ADDRESSES_LH`.
+        let mut buffer = Vec::<u64>::new();
+        loop {
+            buffer.resize((size as usize).div_ceil(8), 0);
+            let first = buffer.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH;
+            // SAFETY: `buffer` holds at least `size` bytes.
+            let ret = unsafe {
+                GetAdaptersAddresses(u32::from(AF_UNSPEC), flags, ptr::null(), first, &mut size)
+            };
+            match ret {
+                NO_ERROR => break,
+                ERROR_BUFFER_OVERFLOW => continue,
+                _ => return Err(io::Error::from_raw_os_error(ret as i32)),
+            }
+        }
+
+        let mut cursor = buffer.as_ptr() as *const IP_ADAPTER_ADDRESSES_LH;
+        while !cursor.is_null() {
+            // SAFETY: the adapters and their names live in `buffer`.
+            let adapter = unsafe { &*cursor };
+            cursor = adapter.Next;
+            let guid = unsafe { CStr::from_ptr(adapter.AdapterName as *const _) };
+            let friendly = unsafe { wide_str(adapter.FriendlyName) };
+            let matches =
+                guid.to_bytes() == name.as_bytes() || String::from_utf16_lossy(friendly) == name;
+            if !matches {
+                continue;
+            }
+            if adapter.PhysicalAddressLength != 6 {
+                break;
+            }
+            let mut octets = [0u8; 6];
+            octets.copy_from_slice(&adapter.PhysicalAddress[..6]);
+            return Ok(Eui(octets));
+        }
+        Err(super::not_found())
+    }
+
+    /// Borrows a NUL-terminated UTF-16 string.
+    unsafe fn wide_str<'a>(s: *const u16) -> &'a [u16] {
+        if s.is_null() {
+            return &[];
+        }
+        let mut len = 0;
+        while *s.add(len) != 0 {
+            len += 1;
+        }
+        std::slice::from_raw_parts(s, len)
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    windows
+)))]
+mod imp {
+    use crate::EUI48;
+    use std::io;
+
+    pub(super) fn from_interface(_name: &str) -> io::Result<EUI48> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "interface addresses are not supported on this platform",
+        ))
+    }
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod tests {
+    use crate::EUI48;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn loopback() {
+        // The loopback interface has an all-zero address on Linux.
+        assert_eq!(EUI48::from_interface("lo").unwrap(), EUI48::NIL);
+    }
+
+    #[test]
+    fn not_found() {
+        let e = EUI48::from_interface("no-such-eui0").unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::NotFound);
+        for name in ["", ".", "..", "../lo"] {
+            let e = EUI48::from_interface(name).unwrap_err();
+            assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Organizationally Unique Identifiers, the vendor prefix of an address.
+
+use crate::{parser, Error, Eui, EuiStr, Width, EUI48};
+use core::convert::TryFrom;
+
+/// The first three octets of an address, as assigned by the IEEE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Oui([u8; 3]);
+
+impl Oui {
+    pub const fn new(bytes: [u8; 3]) -> Oui {
+        Oui(bytes)
+    }
+
+    pub const fn octets(&self) -> [u8; 3] {
+        self.0
+    }
+
+    pub const fn parse_const(s: &str) -> Result<Oui, Error> {
+        match parser::decode(s, true) {
+            Ok(bytes) => Ok(Oui(bytes)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns whether `eui` was allocated under this OUI.
+    pub fn matches(&self, eui: &EUI48) -> bool {
+        eui.oui() == *self
+    }
+
+    pub fn to_canonical_stack(&self) -> EuiStr<8> {
+        EuiStr::canonical(&self.0)
+    }
+}
+
+impl<const N: usize> Eui<N>
+where
+    [u8; N]: Width,
+{
+    pub fn oui(&self) -> Oui {
+        Oui([self.0[0], self.0[1], self.0[2]])
+    }
+}
+
+impl core::convert::TryFrom<&str> for Oui {
+    type Error = crate::Error;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Oui::parse_const(s)
+    }
+}
+
+impl core::str::FromStr for Oui {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Oui::try_from(s)
+    }
+}
+
+impl core::fmt::Display for Oui {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_canonical_stack())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Oui;
+    use crate::{Error, Eui};
+
+    #[test]
+    fn parse() {
+        let expected = Oui([0x0A, 0x1B, 0x2C]);
+        assert_eq!("0A-1B-2C".parse::<Oui>(), Ok(expected));
+        assert_eq!("0a:1b:2c".parse::<Oui>(), Ok(expected));
+        assert_eq!("0A1B2C".parse::<Oui>(), Ok(expected));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!("0A-1B-2".parse::<Oui>(), Err(Error::OddLength));
+        assert_eq!(
+            "0A-1B-2G".parse::<Oui>(),
+            Err(Error::InvalidHexCharacter { c: 'G', index: 7 })
+        );
+        assert_eq!(
+            "0A-1B-2C-3D".parse::<Oui>(),
+            Err(Error::InvalidStringLength)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let oui = Oui([0x0A, 0x1B, 0x2C]);
+        assert_eq!(&*oui.to_canonical_stack(), "0A-1B-2C");
+        assert_eq!(format!("{}", oui), "0A-1B-2C");
+    }
+
+    #[test]
+    fn accessors() {
+        let eui48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let eui64 = Eui([0x0A, 0x1B, 0x2C, 0xFF, 0xFE, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui48.oui().octets(), [0x0A, 0x1B, 0x2C]);
+        assert_eq!(eui64.oui(), eui48.oui());
+    }
+
+    #[test]
+    fn matches() {
+        let oui = Oui([0x0A, 0x1B, 0x2C]);
+        assert!(oui.matches(&Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F])));
+        assert!(!oui.matches(&Eui([0x0A, 0x1B, 0x2D, 0x3D, 0x4E, 0x5F])));
+    }
+}
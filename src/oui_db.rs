@@ -0,0 +1,138 @@
+//! Embedded registry of IEEE MA-L (OUI), MA-M and MA-S assignments.
+//!
+//! The registry that ships with the crate only covers a handful of common
+//! MA-L blocks. Build with `EUI_OUI_CSV` pointing at the IEEE CSV exports to
+//! embed the full registry instead; see `build.rs`.
+
+use crate::{Oui, EUI48};
+#[cfg(feature = "oui-db")]
+use std::collections::HashMap;
+
+/// Key under which [`vendor_histogram`] counts addresses whose OUI is not in
+/// the registry.
+#[cfg(feature = "oui-db")]
+pub const UNKNOWN_VENDOR: &str = "Unknown";
+
+// Sorted by OUI so lookups can binary search.
+#[cfg(not(eui_custom_registry))]
+static REGISTRY: &[([u8; 3], &str)] = &[
+    ([0x00, 0x00, 0x0C], "Cisco Systems, Inc"),
+    ([0x00, 0x00, 0x5E], "ICANN, IANA Department"),
+    ([0x00, 0x01, 0x42], "Cisco Systems, Inc"),
+    ([0x00, 0x03, 0x93], "Apple, Inc."),
+    ([0x00, 0x05, 0x69], "VMware, Inc."),
+    ([0x00, 0x05, 0x85], "Juniper Networks"),
+    ([0x00, 0x0A, 0x95], "Apple, Inc."),
+    ([0x00, 0x0C, 0x29], "VMware, Inc."),
+    ([0x00, 0x0D, 0x3A], "Microsoft Corporation"),
+    ([0x00, 0x15, 0x5D], "Microsoft Corporation"),
+    ([0x00, 0x16, 0x3E], "Xensource, Inc."),
+    ([0x00, 0x17, 0xF2], "Apple, Inc."),
+    ([0x00, 0x1A, 0x11], "Google, Inc."),
+    ([0x00, 0x1B, 0x21], "Intel Corporate"),
+    ([0x00, 0x1B, 0x44], "SanDisk Corporation"),
+    ([0x00, 0x1C, 0x42], "Parallels, Inc."),
+    ([0x00, 0x25, 0x90], "Super Micro Computer, Inc."),
+    ([0x00, 0x50, 0x56], "VMware, Inc."),
+    ([0x00, 0xE0, 0x4C], "Realtek Semiconductor Corp."),
+    ([0x08, 0x00, 0x27], "PCS Systemtechnik GmbH"),
+    ([0xB8, 0x27, 0xEB], "Raspberry Pi Foundation"),
+    ([0xDC, 0xA6, 0x32], "Raspberry Pi Trading Ltd"),
+    ([0xE4, 0x5F, 0x01], "Raspberry Pi Trading Ltd"),
+];
+
+// MA-M (28-bit) and MA-S (36-bit) blocks, with the trailing nibble zeroed.
+#[cfg(not(eui_custom_registry))]
+static REGISTRY_MA_M: &[([u8; 4], &str)] = &[];
+#[cfg(not(eui_custom_registry))]
+static REGISTRY_MA_S: &[([u8; 5], &str)] = &[];
+
+#[cfg(eui_custom_registry)]
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+fn find<const N: usize>(table: &[([u8; N], &'static str)], key: [u8; N]) -> Option<&'static str> {
+    table
+        .binary_search_by(|(k, _)| k.cmp(&key))
+        .ok()
+        .map(|i| table[i].1)
+}
+
+pub fn lookup(oui: [u8; 3]) -> Option<&'static str> {
+    find(REGISTRY, oui)
+}
+
+#[cfg(feature = "oui-db")]
+pub fn vendor_histogram(addrs: &[EUI48]) -> HashMap<&'static str, usize> {
+    let mut histogram = HashMap::new();
+    for addr in addrs {
+        let vendor = addr.vendor().unwrap_or(UNKNOWN_VENDOR);
+        *histogram.entry(vendor).or_insert(0) += 1;
+    }
+    histogram
+}
+
+impl Oui {
+    /// Returns the organization this OUI is registered to, if it is in the
+    /// embedded registry.
+    pub fn vendor(&self) -> Option<&'static str> {
+        lookup(self.octets())
+    }
+}
+
+impl EUI48 {
+    /// Returns the organization the address is registered to, if it is in
+    /// the embedded registry. MA-S and MA-M blocks take precedence over the
+    /// MA-L block they are carved out of.
+    pub fn vendor(&self) -> Option<&'static str> {
+        let b = self.0;
+        find(REGISTRY_MA_S, [b[0], b[1], b[2], b[3], b[4] & 0xF0])
+            .or_else(|| find(REGISTRY_MA_M, [b[0], b[1], b[2], b[3] & 0xF0]))
+            .or_else(|| self.oui().vendor())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lookup, REGISTRY, REGISTRY_MA_M, REGISTRY_MA_S};
+    #[cfg(feature = "oui-db")]
+    use super::{vendor_histogram, UNKNOWN_VENDOR};
+    use crate::{Eui, Oui};
+
+    #[test]
+    fn registry_is_sorted() {
+        assert!(REGISTRY.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(REGISTRY_MA_M.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(REGISTRY_MA_S.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn lookup_known_and_unknown() {
+        assert_eq!(lookup([0x00, 0x50, 0x56]), Some("VMware, Inc."));
+        assert_eq!(lookup([0x0A, 0x1B, 0x2C]), None);
+    }
+
+    #[test]
+    fn vendor() {
+        let eui = Eui([0xB8, 0x27, 0xEB, 0x00, 0x00, 0x03]);
+        assert_eq!(eui.vendor(), Some("Raspberry Pi Foundation"));
+        assert_eq!(Oui::new([0x00, 0x0C, 0x29]).vendor(), Some("VMware, Inc."));
+        assert_eq!(Oui::new([0x0A, 0x1B, 0x2C]).vendor(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "oui-db")]
+    fn histogram() {
+        let addrs = [
+            Eui([0x00, 0x50, 0x56, 0x00, 0x00, 0x01]),
+            Eui([0x00, 0x0C, 0x29, 0x00, 0x00, 0x02]),
+            Eui([0xB8, 0x27, 0xEB, 0x00, 0x00, 0x03]),
+            Eui([0x00, 0x50, 0x56, 0x00, 0x00, 0x04]),
+            Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+        ];
+        let histogram = vendor_histogram(&addrs);
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram["VMware, Inc."], 3);
+        assert_eq!(histogram["Raspberry Pi Foundation"], 1);
+        assert_eq!(histogram[UNKNOWN_VENDOR], 1);
+    }
+}
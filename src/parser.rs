@@ -0,0 +1,160 @@
+use crate::Error;
+
+/// Decodes the hex digits of `s`, skipping separators if `separated`.
+///
+/// The input is walked in place rather than collected, so nothing is
+/// allocated, and the function is `const` so that the `eui48!` and `eui64!`
+/// macros can validate literals at compile time.
+pub(crate) const fn decode<const N: usize>(s: &str, separated: bool) -> Result<[u8; N], Error> {
+    let s = s.as_bytes();
+
+    let mut len = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if !(separated && is_separator(s[i])) {
+            len += 1;
+        }
+        i += 1;
+    }
+    if len % 2 != 0 {
+        return Err(Error::OddLength);
+    }
+
+    if let Err(e) = check_digits(s, separated) {
+        return Err(e);
+    }
+    if len != 2 * N {
+        return Err(Error::InvalidStringLength);
+    }
+
+    let mut out = [0u8; N];
+    let mut digit = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if !(separated && is_separator(s[i])) {
+            out[digit / 2] |= hex_value(s[i]) << (4 * (1 - digit % 2));
+            digit += 1;
+        }
+        i += 1;
+    }
+    Ok(out)
+}
+
+/// Like `decode`, but first pads single-digit groups with a leading zero.
+/// Groups of more than two digits fail at the digit where a separator was
+/// expected.
+pub(crate) fn decode_loose(s: &str) -> Result<[u8; 6], Error> {
+    check_digits(s.as_bytes(), true)?;
+
+    // The padded digits are staged on the stack; anything past the twelve an
+    // EUI-48 needs is only counted, which is enough to report the error.
+    let mut digits = [0u8; 12];
+    let mut len = 0;
+    for group in s.split(|c: char| c.is_ascii() && is_separator(c as u8)) {
+        if let Some(&c) = group.as_bytes().get(2) {
+            // Groups are subslices of `s`, so their offsets can be recovered.
+            let offset = group.as_ptr() as usize - s.as_ptr() as usize;
+            return Err(Error::InvalidSeparator {
+                c: c as char,
+                index: offset + 2,
+            });
+        }
+        let padding = if group.len() == 1 { &b"0"[..] } else { &[] };
+        for &c in padding.iter().chain(group.as_bytes()) {
+            if let Some(digit) = digits.get_mut(len) {
+                *digit = c;
+            }
+            len += 1;
+        }
+    }
+
+    if len % 2 != 0 {
+        return Err(Error::OddLength);
+    }
+    if len != digits.len() {
+        return Err(Error::InvalidStringLength);
+    }
+    // Only ASCII hex digits made it here.
+    decode(core::str::from_utf8(&digits).unwrap_or_default(), false)
+}
+
+/// Fails on the first byte of `s` that is neither a hex digit nor, if
+/// `separated`, a separator.
+pub(crate) const fn check_digits(s: &[u8], separated: bool) -> Result<(), Error> {
+    let mut i = 0;
+    while i < s.len() {
+        if !(s[i].is_ascii_hexdigit() || separated && is_separator(s[i])) {
+            return Err(Error::InvalidHexCharacter {
+                c: char_at(s, i),
+                index: i,
+            });
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Decodes the character of the UTF-8 string `s` starting at byte `i`.
+pub(crate) const fn char_at(s: &[u8], i: usize) -> char {
+    let (len, mut c) = match s[i] {
+        b @ 0x00..=0x7F => (1, b as u32),
+        b @ 0x80..=0xDF => (2, (b & 0x1F) as u32),
+        b @ 0xE0..=0xEF => (3, (b & 0x0F) as u32),
+        b => (4, (b & 0x07) as u32),
+    };
+    let mut k = 1;
+    while k < len && i + k < s.len() {
+        c = c << 6 | (s[i + k] & 0x3F) as u32;
+        k += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+/// The characters `TryFrom<&str>` skips between digits: the separators of
+/// every [`Format`](crate::Format), plus the other ASCII whitespace that
+/// console and `ipconfig` output may contain.
+pub(crate) const SEPARATORS: &str = ".:- \t\r\n";
+
+pub(crate) const fn is_separator(c: u8) -> bool {
+    matches!(c, b'.' | b':' | b'-' | b' ' | b'\t' | b'\r' | b'\n')
+}
+
+pub(crate) const fn hex_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        _ => c - b'A' + 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Eui, EUI48};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn invalid_character_position() {
+        assert_eq!(
+            EUI48::try_from("0A-1B-2C-3D-4E-é"),
+            Err(Error::InvalidHexCharacter { c: 'é', index: 15 })
+        );
+        assert_eq!(
+            EUI48::from_loose_fmt("A-1B-2C-3D-4E-💥"),
+            Err(Error::InvalidHexCharacter {
+                c: '💥', index: 14
+            })
+        );
+    }
+
+    #[test]
+    fn whitespace_separators() {
+        let expected = Ok(Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(EUI48::try_from("0A 1B 2C 3D 4E 5F"), expected);
+        assert_eq!(EUI48::try_from("0A\t1B\t2C\t3D\t4E\t5F\r\n"), expected);
+        assert_eq!(EUI48::try_from("0A1B2C\n3D4E5F"), expected);
+        assert_eq!(EUI48::from_loose_fmt("A\t1B\t2C\t3D\t4E\t5F"), expected);
+    }
+}
@@ -0,0 +1,148 @@
+//! Address prefixes, such as MA-M and MA-S assignments or local policy
+//! blocks.
+
+use crate::{Error, Eui48Range, EUI, EUI48};
+use core::convert::TryFrom;
+
+/// The addresses sharing their first `prefix_len` bits with a base address,
+/// written `AC-DE-48-00-00-00/28`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EuiPrefix {
+    base: EUI48,
+    prefix_len: u8,
+}
+
+/// Returns the mask of the first `bits` bits of a 48-bit value.
+fn mask(bits: u8) -> u64 {
+    !(u64::MAX >> bits) >> 16
+}
+
+impl EuiPrefix {
+    /// Builds the prefix of the first `prefix_len` bits of `base`; the bits
+    /// after them are cleared.
+    pub fn new(base: EUI48, prefix_len: u8) -> Result<EuiPrefix, Error> {
+        if prefix_len > 48 {
+            return Err(Error::InvalidPrefix);
+        }
+        let base = EUI48::from_u64(base.to_u64() & mask(prefix_len))?;
+        Ok(EuiPrefix { base, prefix_len })
+    }
+
+    /// Builds a prefix from a mask such as `FF-FF-FF-F0-00-00`, which must
+    /// be a run of one bits followed by zero bits.
+    pub fn from_mask(base: EUI48, mask: EUI48) -> Result<EuiPrefix, Error> {
+        let prefix_len = (mask.to_u64() << 16).leading_ones() as u8;
+        if mask.to_u64() != self::mask(prefix_len) {
+            return Err(Error::InvalidPrefix);
+        }
+        EuiPrefix::new(base, prefix_len)
+    }
+
+    pub fn base(&self) -> EUI48 {
+        self.base
+    }
+
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub fn mask(&self) -> EUI48 {
+        // A mask always fits in 48 bits.
+        EUI48::from_u64(mask(self.prefix_len)).unwrap()
+    }
+
+    pub fn matches(&self, eui: &EUI48) -> bool {
+        eui.to_u64() & mask(self.prefix_len) == self.base.to_u64()
+    }
+
+    /// Returns every address under the prefix.
+    pub fn range(&self) -> Eui48Range {
+        let last = self.base.to_u64() | !mask(self.prefix_len) & 0xFFFF_FFFF_FFFF;
+        Eui48Range::new(self.base, EUI48::from_u64(last).unwrap())
+    }
+}
+
+impl TryFrom<&str> for EuiPrefix {
+    type Error = crate::Error;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (base, prefix_len) = s.split_once('/').ok_or(Error::InvalidPrefix)?;
+        let base = EUI48::try_from(base)?;
+        let prefix_len = prefix_len.parse().map_err(|_| Error::InvalidPrefix)?;
+        EuiPrefix::new(base, prefix_len)
+    }
+}
+
+impl core::str::FromStr for EuiPrefix {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EuiPrefix::try_from(s)
+    }
+}
+
+impl core::fmt::Display for EuiPrefix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.base.write_canonical(f)?;
+        write!(f, "/{}", self.prefix_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EuiPrefix;
+    use crate::{Error, Eui, EUI48};
+
+    #[test]
+    fn parse() {
+        let prefix: EuiPrefix = "AC-DE-48-12-34-56/28".parse().unwrap();
+        assert_eq!(prefix.base(), Eui([0xAC, 0xDE, 0x48, 0x10, 0x00, 0x00]));
+        assert_eq!(prefix.prefix_len(), 28);
+        assert_eq!(prefix.to_string(), "AC-DE-48-10-00-00/28");
+        assert_eq!(
+            "AC-DE-48-00-00-00".parse::<EuiPrefix>(),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            "AC-DE-48-00-00-00/49".parse::<EuiPrefix>(),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            "AC-DE-48-00-00-00/x".parse::<EuiPrefix>(),
+            Err(Error::InvalidPrefix)
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let prefix: EuiPrefix = "AC-DE-48-00-00-00/28".parse().unwrap();
+        assert!(prefix.matches(&Eui([0xAC, 0xDE, 0x48, 0x0F, 0xFF, 0xFF])));
+        assert!(!prefix.matches(&Eui([0xAC, 0xDE, 0x48, 0x10, 0x00, 0x00])));
+        let all = EuiPrefix::new(EUI48::BROADCAST, 0).unwrap();
+        assert!(all.matches(&EUI48::NIL));
+    }
+
+    #[test]
+    fn mask() {
+        let mask = Eui([0xFF, 0xFF, 0xFF, 0xF0, 0x00, 0x00]);
+        let prefix = EuiPrefix::from_mask(Eui([0xAC, 0xDE, 0x48, 0, 0, 0]), mask).unwrap();
+        assert_eq!(prefix.prefix_len(), 28);
+        assert_eq!(prefix.mask(), mask);
+        assert_eq!(
+            EuiPrefix::from_mask(EUI48::NIL, Eui([0xFF, 0, 0xFF, 0, 0, 0])),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            EuiPrefix::from_mask(EUI48::NIL, EUI48::BROADCAST).map(|p| p.prefix_len()),
+            Ok(48)
+        );
+    }
+
+    #[test]
+    fn range() {
+        let prefix: EuiPrefix = "AC-DE-48-00-00-00/44".parse().unwrap();
+        let range = prefix.range();
+        assert_eq!(range.len(), 16);
+        assert_eq!(range.end(), Eui([0xAC, 0xDE, 0x48, 0x00, 0x00, 0x0F]));
+        let host: EuiPrefix = "AC-DE-48-00-00-01/48".parse().unwrap();
+        assert_eq!(host.range().len(), 1);
+    }
+}
@@ -0,0 +1,160 @@
+//! Longest-prefix-match maps keyed by address prefixes.
+
+use crate::{EuiPrefix, EUI48};
+use alloc::collections::BTreeMap;
+
+/// Maps [`EuiPrefix`]es of any length to values, resolving each address
+/// against the longest prefix that matches it.
+#[derive(Clone, Debug)]
+pub struct PrefixMap<V> {
+    entries: BTreeMap<EuiPrefix, V>,
+    // The number of entries of each prefix length, so lookups only probe
+    // lengths in use.
+    lengths: [usize; 49],
+}
+
+impl<V> Default for PrefixMap<V> {
+    fn default() -> Self {
+        PrefixMap {
+            entries: BTreeMap::new(),
+            lengths: [0; 49],
+        }
+    }
+}
+
+impl<V> PrefixMap<V> {
+    pub fn new() -> Self {
+        PrefixMap::default()
+    }
+
+    /// Maps `prefix` to `value`, returning the value it replaces.
+    pub fn insert(&mut self, prefix: EuiPrefix, value: V) -> Option<V> {
+        let old = self.entries.insert(prefix, value);
+        if old.is_none() {
+            self.lengths[usize::from(prefix.prefix_len())] += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, prefix: &EuiPrefix) -> Option<V> {
+        let old = self.entries.remove(prefix);
+        if old.is_some() {
+            self.lengths[usize::from(prefix.prefix_len())] -= 1;
+        }
+        old
+    }
+
+    /// Returns the value of exactly `prefix`.
+    pub fn get(&self, prefix: &EuiPrefix) -> Option<&V> {
+        self.entries.get(prefix)
+    }
+
+    /// Returns the longest prefix that matches `eui`, and its value.
+    pub fn lookup_prefix(&self, eui: &EUI48) -> Option<(EuiPrefix, &V)> {
+        (0..=48u8)
+            .rev()
+            .filter(|len| self.lengths[usize::from(*len)] > 0)
+            .find_map(|len| {
+                let prefix = EuiPrefix::new(*eui, len).ok()?;
+                self.entries.get(&prefix).map(|value| (prefix, value))
+            })
+    }
+
+    /// Returns the value of the longest prefix that matches `eui`.
+    pub fn lookup(&self, eui: &EUI48) -> Option<&V> {
+        self.lookup_prefix(eui).map(|(_, value)| value)
+    }
+
+    /// Iterates over the entries, ordered by base address, then by prefix
+    /// length.
+    pub fn iter(&self) -> impl Iterator<Item = (&EuiPrefix, &V)> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<V> Extend<(EuiPrefix, V)> for PrefixMap<V> {
+    fn extend<I: IntoIterator<Item = (EuiPrefix, V)>>(&mut self, iter: I) {
+        for (prefix, value) in iter {
+            self.insert(prefix, value);
+        }
+    }
+}
+
+impl<V> core::iter::FromIterator<(EuiPrefix, V)> for PrefixMap<V> {
+    fn from_iter<I: IntoIterator<Item = (EuiPrefix, V)>>(iter: I) -> Self {
+        let mut map = PrefixMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrefixMap;
+    use crate::{Eui, EuiPrefix, EUI48};
+
+    fn prefix(s: &str) -> EuiPrefix {
+        s.parse().unwrap()
+    }
+
+    fn map() -> PrefixMap<&'static str> {
+        vec![
+            (prefix("00-50-C2-00-00-00/24"), "MA-L"),
+            (prefix("00-50-C2-00-30-00/36"), "MA-S"),
+            (prefix("00-00-00-00-00-00/0"), "default"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let map = map();
+        let eui = Eui([0x00, 0x50, 0xC2, 0x00, 0x3A, 0xBC]);
+        assert_eq!(map.lookup(&eui), Some(&"MA-S"));
+        let eui = Eui([0x00, 0x50, 0xC2, 0x00, 0x4A, 0xBC]);
+        assert_eq!(
+            map.lookup_prefix(&eui),
+            Some((prefix("00-50-C2-00-00-00/24"), &"MA-L"))
+        );
+        assert_eq!(map.lookup(&EUI48::BROADCAST), Some(&"default"));
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut map = map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(
+            map.insert(prefix("00-50-C2-00-30-00/36"), "lab"),
+            Some("MA-S")
+        );
+        assert_eq!(map.get(&prefix("00-50-C2-00-30-00/36")), Some(&"lab"));
+        assert_eq!(map.remove(&prefix("00-00-00-00-00-00/0")), Some("default"));
+        assert_eq!(map.remove(&prefix("00-00-00-00-00-00/0")), None);
+        assert_eq!(map.lookup(&EUI48::BROADCAST), None);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn iter() {
+        let map = map();
+        let prefixes: Vec<_> = map.iter().map(|(prefix, _)| prefix.to_string()).collect();
+        assert_eq!(
+            prefixes,
+            [
+                "00-00-00-00-00-00/0",
+                "00-50-C2-00-00-00/24",
+                "00-50-C2-00-30-00/36"
+            ]
+        );
+        assert!(PrefixMap::<()>::new().is_empty());
+    }
+}
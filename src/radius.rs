@@ -0,0 +1,131 @@
+//! RADIUS Calling-Station-Id and Called-Station-Id conventions.
+
+use crate::{Case, Error, Format, EUI, EUI48};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The way a NAS vendor expects addresses in station-id attributes, so that
+/// AAA integrations can pick one by name from configuration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StationIdProfile {
+    /// `0A-1B-2C-3D-4E-5F`, as recommended by RFC 3580. Named `upper-hyphen`.
+    UpperHyphen,
+    /// `0a:1b:2c:3d:4e:5f`. Named `lower-colon`.
+    LowerColon,
+    /// `0a1b2c3d4e5f`. Named `bare-lower`.
+    BareLower,
+    /// `0a1b.2c3d.4e5f`, as sent by Cisco IOS. Named `cisco-dot`.
+    CiscoDot,
+}
+
+impl StationIdProfile {
+    pub const ALL: [StationIdProfile; 4] = [
+        StationIdProfile::UpperHyphen,
+        StationIdProfile::LowerColon,
+        StationIdProfile::BareLower,
+        StationIdProfile::CiscoDot,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            StationIdProfile::UpperHyphen => "upper-hyphen",
+            StationIdProfile::LowerColon => "lower-colon",
+            StationIdProfile::BareLower => "bare-lower",
+            StationIdProfile::CiscoDot => "cisco-dot",
+        }
+    }
+
+    pub const fn format(self) -> Format {
+        match self {
+            StationIdProfile::UpperHyphen => Format::Canonical,
+            StationIdProfile::LowerColon => Format::Colon,
+            StationIdProfile::BareLower => Format::Bare,
+            StationIdProfile::CiscoDot => Format::Dot,
+        }
+    }
+
+    pub const fn case(self) -> Case {
+        match self {
+            StationIdProfile::UpperHyphen => Case::Upper,
+            _ => Case::Lower,
+        }
+    }
+}
+
+/// Looks a profile up by its name, ignoring ASCII case.
+impl core::str::FromStr for StationIdProfile {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StationIdProfile::ALL
+            .iter()
+            .copied()
+            .find(|profile| profile.name().eq_ignore_ascii_case(s))
+            .ok_or(Error::UnknownProfile)
+    }
+}
+
+impl core::fmt::Display for StationIdProfile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl EUI48 {
+    pub fn write_station_id(
+        &self,
+        w: &mut impl core::fmt::Write,
+        profile: StationIdProfile,
+    ) -> core::fmt::Result {
+        self.write_format_with(w, profile.format(), profile.case())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_station_id(&self, profile: StationIdProfile) -> String {
+        self.to_fmt_with(profile.format(), profile.case())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StationIdProfile;
+    use crate::{Error, Eui, EUI48};
+
+    const EUI: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+
+    #[test]
+    fn write_station_id() {
+        let expected = [
+            "0A-1B-2C-3D-4E-5F",
+            "0a:1b:2c:3d:4e:5f",
+            "0a1b2c3d4e5f",
+            "0a1b.2c3d.4e5f",
+        ];
+        for (profile, expected) in StationIdProfile::ALL.iter().zip(expected) {
+            let mut s = String::new();
+            EUI.write_station_id(&mut s, *profile).unwrap();
+            assert_eq!(s, expected);
+            assert_eq!(s.parse(), Ok(EUI));
+        }
+    }
+
+    #[test]
+    fn names() {
+        for profile in StationIdProfile::ALL {
+            assert_eq!(profile.to_string().parse(), Ok(profile));
+        }
+        assert_eq!("Cisco-Dot".parse(), Ok(StationIdProfile::CiscoDot));
+        assert_eq!(
+            "upper-colon".parse::<StationIdProfile>(),
+            Err(Error::UnknownProfile)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_station_id() {
+        assert_eq!(
+            EUI.to_station_id(StationIdProfile::LowerColon),
+            "0a:1b:2c:3d:4e:5f"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Random address generation.
+
+use crate::{Eui, EUI48, EUI64};
+use rand::Rng;
+
+impl EUI48 {
+    /// Generates a random locally administered unicast address, e.g. for a
+    /// virtual machine's NIC.
+    pub fn random_local_unicast(rng: &mut impl Rng) -> EUI48 {
+        let mut bytes = [0u8; 6];
+        rng.fill_bytes(&mut bytes);
+        Eui(bytes).with_local_bit(true).with_multicast_bit(false)
+    }
+}
+
+impl EUI64 {
+    /// Generates a random locally administered unicast address.
+    pub fn random_local_unicast(rng: &mut impl Rng) -> EUI64 {
+        let mut bytes = [0u8; 8];
+        rng.fill_bytes(&mut bytes);
+        Eui(bytes).with_local_bit(true).with_multicast_bit(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EUI48, EUI64};
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn eui48_random_local_unicast() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..64 {
+            let eui = EUI48::random_local_unicast(&mut rng);
+            assert!(eui.is_locally_administered() && eui.is_unicast());
+        }
+        assert_ne!(
+            EUI48::random_local_unicast(&mut rng),
+            EUI48::random_local_unicast(&mut rng)
+        );
+    }
+
+    #[test]
+    fn eui64_random_local_unicast() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..64 {
+            let eui = EUI64::random_local_unicast(&mut rng);
+            assert!(eui.is_locally_administered() && eui.is_unicast());
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Inclusive ranges of addresses, e.g. address pools.
+
+use crate::{Error, EUI48, EUI64};
+use core::convert::TryFrom;
+
+macro_rules! eui_range {
+    ($(#[$doc:meta])* $name:ident, $eui:ident, $len:ty) => {
+        $(#[$doc])*
+        ///
+        /// Like [`RangeInclusive`](core::ops::RangeInclusive), the range is
+        /// its own iterator, and is empty once iterated over or if its start
+        /// is above its end.
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name {
+            start: $eui,
+            end: $eui,
+            exhausted: bool,
+        }
+
+        impl $name {
+            pub fn new(start: $eui, end: $eui) -> $name {
+                $name {
+                    start,
+                    end,
+                    exhausted: false,
+                }
+            }
+
+            /// Builds the range of `count` addresses from `start`, failing
+            /// with `OutOfRange` if it would run past the broadcast address.
+            pub fn with_count(start: $eui, count: u64) -> Result<$name, Error> {
+                match count.checked_sub(1) {
+                    None => Ok($name {
+                        start,
+                        end: start,
+                        exhausted: true,
+                    }),
+                    Some(last) => start
+                        .checked_add(last)
+                        .map(|end| $name::new(start, end))
+                        .ok_or(Error::OutOfRange),
+                }
+            }
+
+            pub fn start(&self) -> $eui {
+                self.start
+            }
+
+            pub fn end(&self) -> $eui {
+                self.end
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.exhausted || self.start > self.end
+            }
+
+            /// Returns the number of addresses left in the range.
+            pub fn len(&self) -> $len {
+                if self.is_empty() {
+                    return 0;
+                }
+                <$len>::from(self.end.to_u64() - self.start.to_u64()) + 1
+            }
+
+            pub fn contains(&self, eui: &$eui) -> bool {
+                !self.is_empty() && self.start <= *eui && *eui <= self.end
+            }
+
+            /// Returns the addresses in both ranges, or `None` if they are
+            /// disjoint.
+            pub fn intersection(&self, other: &$name) -> Option<$name> {
+                let range = $name::new(self.start.max(other.start), self.end.min(other.end));
+                if self.is_empty() || other.is_empty() || range.is_empty() {
+                    return None;
+                }
+                Some(range)
+            }
+
+            pub fn overlaps(&self, other: &$name) -> bool {
+                self.intersection(other).is_some()
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = $eui;
+
+            fn next(&mut self) -> Option<$eui> {
+                if self.is_empty() {
+                    return None;
+                }
+                let eui = self.start;
+                match self.start.succ() {
+                    Some(next) if eui < self.end => self.start = next,
+                    _ => self.exhausted = true,
+                }
+                Some(eui)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<$eui> {
+                if self.is_empty() {
+                    return None;
+                }
+                let eui = self.end;
+                match self.end.pred() {
+                    Some(previous) if eui > self.start => self.end = previous,
+                    _ => self.exhausted = true,
+                }
+                Some(eui)
+            }
+        }
+
+        impl core::iter::FusedIterator for $name {}
+    };
+}
+
+impl EUI48 {
+    /// Yields `start` and the addresses after it, endlessly: the broadcast
+    /// address is followed by the nil address. Use [`Eui48Range`] to stop
+    /// at the broadcast address instead.
+    pub fn iter_from(start: EUI48) -> impl Iterator<Item = EUI48> {
+        core::iter::successors(Some(start), |eui| Some(eui.wrapping_add(1)))
+    }
+}
+
+impl EUI64 {
+    /// Yields `start` and the addresses after it, endlessly: the broadcast
+    /// address is followed by the nil address. Use [`Eui64Range`] to stop
+    /// at the broadcast address instead.
+    pub fn iter_from(start: EUI64) -> impl Iterator<Item = EUI64> {
+        core::iter::successors(Some(start), |eui| Some(eui.wrapping_add(1)))
+    }
+}
+
+eui_range!(
+    /// An inclusive range of EUI-48s.
+    Eui48Range,
+    EUI48,
+    u64
+);
+
+eui_range!(
+    /// An inclusive range of EUI-64s. Its length is a `u128`, since the
+    /// full range holds 2^64 addresses.
+    Eui64Range,
+    EUI64,
+    u128
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{Eui48Range, Eui64Range};
+    use crate::{Error, Eui, EUI48, EUI64};
+
+    const BASE: EUI48 = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+
+    fn eui(offset: u64) -> EUI48 {
+        BASE.checked_add(offset).unwrap()
+    }
+
+    #[test]
+    fn iterate() {
+        let range = Eui48Range::with_count(BASE, 3).unwrap();
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(range.clone().collect::<Vec<_>>(), [eui(0), eui(1), eui(2)]);
+        assert_eq!(range.rev().collect::<Vec<_>>(), [eui(2), eui(1), eui(0)]);
+    }
+
+    #[test]
+    fn exhausted() {
+        let mut range = Eui48Range::new(BASE, eui(1));
+        assert_eq!(range.next_back(), Some(eui(1)));
+        assert_eq!(range.next(), Some(BASE));
+        assert!(range.is_empty());
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert!(!range.contains(&BASE));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Eui48Range::new(eui(1), BASE).is_empty());
+        assert!(Eui48Range::with_count(BASE, 0).unwrap().is_empty());
+        assert_eq!(Eui48Range::with_count(BASE, 0).unwrap().next(), None);
+    }
+
+    #[test]
+    fn with_count_out_of_range() {
+        assert_eq!(
+            Eui48Range::with_count(EUI48::BROADCAST, 2),
+            Err(Error::OutOfRange)
+        );
+        let range = Eui48Range::with_count(EUI48::BROADCAST, 1).unwrap();
+        assert_eq!(range.collect::<Vec<_>>(), [EUI48::BROADCAST]);
+    }
+
+    #[test]
+    fn contains() {
+        let range = Eui48Range::new(BASE, eui(9));
+        assert!(range.contains(&BASE));
+        assert!(range.contains(&eui(9)));
+        assert!(!range.contains(&eui(10)));
+        assert!(!range.contains(&EUI48::NIL));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Eui48Range::new(BASE, eui(9));
+        let b = Eui48Range::new(eui(5), eui(20));
+        let c = Eui48Range::new(eui(10), eui(20));
+        assert_eq!(a.intersection(&b), Some(Eui48Range::new(eui(5), eui(9))));
+        assert_eq!(a.intersection(&c), None);
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&c));
+        assert!(!a.overlaps(&Eui48Range::with_count(BASE, 0).unwrap()));
+    }
+
+    #[test]
+    fn eui64() {
+        let full = Eui64Range::new(EUI64::NIL, EUI64::BROADCAST);
+        assert_eq!(full.len(), 1 << 64);
+        assert_eq!(full.size_hint(), (usize::MAX, None));
+        let mut top = Eui64Range::with_count(EUI64::BROADCAST.pred().unwrap(), 2).unwrap();
+        assert_eq!(top.next(), EUI64::BROADCAST.pred());
+        assert_eq!(top.next(), Some(EUI64::BROADCAST));
+        assert_eq!(top.next(), None);
+    }
+
+    #[test]
+    fn iter_from() {
+        let unicast = EUI48::iter_from(BASE).filter(|eui| eui.is_unicast());
+        assert_eq!(unicast.take(2).collect::<Vec<_>>(), [eui(0), eui(1)]);
+        let wrapped = EUI48::iter_from(EUI48::BROADCAST).take(2);
+        assert_eq!(wrapped.collect::<Vec<_>>(), [EUI48::BROADCAST, EUI48::NIL]);
+        let wrapped = EUI64::iter_from(EUI64::BROADCAST).nth(1);
+        assert_eq!(wrapped, Some(EUI64::NIL));
+    }
+}
@@ -0,0 +1,59 @@
+//! Serialize an address in the lowercase dot (Cisco) format, e.g.
+//! `0a1b.2c3d.4e5f`, using `#[serde(with = "eui::serde_dot")]`.
+
+use crate::{Case, Format, EUI};
+use alloc::string::String;
+use core::convert::TryFrom;
+use serde::de::{self, Deserialize, Deserializer, Unexpected};
+use serde::Serializer;
+
+pub fn serialize<T, S>(eui: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: EUI,
+    S: Serializer,
+{
+    serializer.serialize_str(&eui.to_fmt_with(Format::Dot, Case::Lower))
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: for<'a> TryFrom<&'a str, Error = crate::Error>,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    T::try_from(&s).map_err(|_| de::Error::invalid_value(Unexpected::Str(&s), &"a dotted EUI"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EUI48, EUI64};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_dot")]
+        mac: EUI48,
+        #[serde(with = "crate::serde_dot")]
+        eui64: EUI64,
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let config = Config {
+            mac: Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+            eui64: Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"mac":"0a1b.2c3d.4e5f","eui64":"00ff.0a1b.2c3d.4e5f"}"#
+        );
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn json_invalid() {
+        let json = r#"{"mac":"0a1b.2c3d.4e","eui64":"00ff.0a1b.2c3d.4e5f"}"#;
+        assert!(serde_json::from_str::<Config>(json).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! Serializes addresses as canonical strings in human-readable formats and
+//! as fixed-size byte arrays otherwise.
+
+use crate::{Eui, EUI48, EUI64};
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::{Serialize, Serializer};
+
+impl Serialize for EUI48 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_canonical_stack())
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl Serialize for EUI64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_canonical_stack())
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EUI48 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor(PhantomData))
+        } else {
+            <[u8; 6]>::deserialize(deserializer).map(Eui)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EUI64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor(PhantomData))
+        } else {
+            <[u8; 8]>::deserialize(deserializer).map(Eui)
+        }
+    }
+}
+
+struct StrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for StrVisitor<T>
+where
+    T: for<'a> TryFrom<&'a str, Error = crate::Error>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an EUI string")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        T::try_from(s).map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EUI48, EUI64};
+    use serde_test::{assert_tokens, Configure, Token};
+
+    #[test]
+    fn eui48_json() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let json = serde_json::to_string(&eui).unwrap();
+        assert_eq!(json, r#""0A-1B-2C-3D-4E-5F""#);
+        assert_eq!(serde_json::from_str::<EUI48>(&json).unwrap(), eui);
+    }
+
+    #[test]
+    fn eui48_json_any_style() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        for json in [r#""0a:1b:2c:3d:4e:5f""#, r#""0A1B.2C3D.4E5F""#] {
+            assert_eq!(serde_json::from_str::<EUI48>(json).unwrap(), eui);
+        }
+        assert!(serde_json::from_str::<EUI48>(r#""0A-1B-2C""#).is_err());
+    }
+
+    #[test]
+    fn eui64_json() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let json = serde_json::to_string(&eui).unwrap();
+        assert_eq!(json, r#""00-FF-0A-1B-2C-3D-4E-5F""#);
+        assert_eq!(serde_json::from_str::<EUI64>(&json).unwrap(), eui);
+    }
+
+    #[test]
+    fn eui48_readable_tokens() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_tokens(&eui.readable(), &[Token::Str("0A-1B-2C-3D-4E-5F")]);
+    }
+
+    #[test]
+    fn eui48_compact_tokens() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_tokens(
+            &eui.compact(),
+            &[
+                Token::Tuple { len: 6 },
+                Token::U8(0x0A),
+                Token::U8(0x1B),
+                Token::U8(0x2C),
+                Token::U8(0x3D),
+                Token::U8(0x4E),
+                Token::U8(0x5F),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn eui64_compact_tokens() {
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_tokens(
+            &eui.compact(),
+            &[
+                Token::Tuple { len: 8 },
+                Token::U8(0x00),
+                Token::U8(0xFF),
+                Token::U8(0x0A),
+                Token::U8(0x1B),
+                Token::U8(0x2C),
+                Token::U8(0x3D),
+                Token::U8(0x4E),
+                Token::U8(0x5F),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! IEEE 802c Structured Local Address Plan (SLAP).
+
+use crate::{parser, Error, Eui, EuiStr, Width, EUI48, EUI64};
+use core::convert::TryFrom;
+
+/// The SLAP quadrant of a locally administered address, selected by the Y
+/// and Z bits (`0x04` and `0x08`) of its first octet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SlapQuadrant {
+    /// Extended Local Identifier, allocated under a CID (`x'A'`).
+    Eli,
+    /// Standard Assigned Identifier, allocated by a protocol (`x'E'`).
+    Sai,
+    /// Administratively Assigned Identifier (`x'2'`).
+    Aai,
+    /// Reserved for future use (`x'6'`).
+    Reserved,
+}
+
+impl SlapQuadrant {
+    fn from_octet(octet: u8) -> Option<SlapQuadrant> {
+        if octet & 0x02 == 0 {
+            return None;
+        }
+        Some(match octet & 0x0C {
+            0x08 => SlapQuadrant::Eli,
+            0x0C => SlapQuadrant::Sai,
+            0x00 => SlapQuadrant::Aai,
+            _ => SlapQuadrant::Reserved,
+        })
+    }
+}
+
+/// An IEEE Company ID, the prefix of ELI addresses.
+///
+/// Unlike an [`Oui`](crate::Oui), a CID has the U/L bit set: its first octet
+/// ends in the `x'A'` nibble of the ELI quadrant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cid([u8; 3]);
+
+impl Cid {
+    /// Wraps `bytes`, failing with `InvalidPrefix` if they don't end the
+    /// first octet in `x'A'`.
+    pub const fn new(bytes: [u8; 3]) -> Result<Cid, Error> {
+        if bytes[0] & 0x0F != 0x0A {
+            return Err(Error::InvalidPrefix);
+        }
+        Ok(Cid(bytes))
+    }
+
+    pub const fn octets(&self) -> [u8; 3] {
+        self.0
+    }
+
+    pub const fn parse_const(s: &str) -> Result<Cid, Error> {
+        match parser::decode(s, true) {
+            Ok(bytes) => Cid::new(bytes),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Builds the ELI address with this CID and a 24-bit extension.
+    pub fn eli_eui48(&self, extension: u32) -> Result<EUI48, Error> {
+        EUI48::from_eli(*self, extension)
+    }
+
+    /// Builds the ELI address with this CID and a 40-bit extension.
+    pub fn eli_eui64(&self, extension: u64) -> Result<EUI64, Error> {
+        EUI64::from_eli(*self, extension)
+    }
+
+    pub fn to_canonical_stack(&self) -> EuiStr<8> {
+        EuiStr::canonical(&self.0)
+    }
+}
+
+impl TryFrom<&str> for Cid {
+    type Error = crate::Error;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Cid::parse_const(s)
+    }
+}
+
+impl core::str::FromStr for Cid {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cid::try_from(s)
+    }
+}
+
+impl core::fmt::Display for Cid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_canonical_stack())
+    }
+}
+
+impl<const N: usize> Eui<N>
+where
+    [u8; N]: Width,
+{
+    /// Returns the SLAP quadrant of a locally administered address, or `None`
+    /// if the address is universally administered.
+    pub fn slap_quadrant(&self) -> Option<SlapQuadrant> {
+        SlapQuadrant::from_octet(self.0[0])
+    }
+
+    /// Returns the Company ID of an ELI address, group addresses included.
+    pub fn cid(&self) -> Option<Cid> {
+        match self.slap_quadrant() {
+            // The I/G bit is not part of the CID.
+            Some(SlapQuadrant::Eli) => Some(Cid([self.0[0] & !0x01, self.0[1], self.0[2]])),
+            _ => None,
+        }
+    }
+}
+
+impl EUI48 {
+    /// Builds an ELI address from a Company ID and a 24-bit extension.
+    pub fn from_eli(cid: Cid, extension: u32) -> Result<EUI48, Error> {
+        EUI48::from_oui_and_u24(cid.0, extension)
+    }
+}
+
+impl EUI64 {
+    /// Builds an ELI address from a Company ID and a 40-bit extension.
+    pub fn from_eli(cid: Cid, extension: u64) -> Result<EUI64, Error> {
+        if extension > 0xFF_FFFF_FFFF {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut raw_address: [u8; 8] = Default::default();
+        raw_address[..3].copy_from_slice(&cid.0);
+        raw_address[3..].copy_from_slice(&extension.to_be_bytes()[3..]);
+        Ok(Eui(raw_address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cid;
+    use crate::{Error, Eui, SlapQuadrant, EUI48, EUI64};
+
+    #[test]
+    fn quadrants() {
+        let quadrant = |octet| Eui([octet, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]).slap_quadrant();
+        assert_eq!(quadrant(0x0A), Some(SlapQuadrant::Eli));
+        assert_eq!(quadrant(0x0E), Some(SlapQuadrant::Sai));
+        assert_eq!(quadrant(0x02), Some(SlapQuadrant::Aai));
+        assert_eq!(quadrant(0x06), Some(SlapQuadrant::Reserved));
+        assert_eq!(quadrant(0x1B), Some(SlapQuadrant::Eli));
+        assert_eq!(quadrant(0x08), None);
+    }
+
+    #[test]
+    fn eui64_quadrant() {
+        let eui = Eui([0xFE, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.slap_quadrant(), Some(SlapQuadrant::Sai));
+    }
+
+    #[test]
+    fn eui48_from_eli() {
+        let cid = Cid([0x1A, 0x1B, 0x2C]);
+        let eui = EUI48::from_eli(cid, 0x3D_4E5F).unwrap();
+        assert_eq!(eui, [0x1A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(eui.slap_quadrant(), Some(SlapQuadrant::Eli));
+        assert_eq!(eui.cid(), Some(cid));
+        assert_eq!(cid.eli_eui48(0x3D_4E5F), Ok(eui));
+    }
+
+    #[test]
+    fn eui48_from_eli_invalid() {
+        assert_eq!(
+            EUI48::from_eli(Cid([0x0A, 0x1B, 0x2C]), 0x100_0000),
+            Err(Error::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn eui64_from_eli() {
+        let cid = Cid([0x0A, 0x1B, 0x2C]);
+        let eui = EUI64::from_eli(cid, 0x3D_4E5F_6071).unwrap();
+        assert_eq!(eui, [0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F, 0x60, 0x71]);
+        assert_eq!(eui.cid(), Some(cid));
+        assert_eq!(cid.eli_eui64(0x100_0000_0000), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn cid() {
+        assert_eq!(Cid::new([0x0A, 0x1B, 0x2C]), Ok(Cid([0x0A, 0x1B, 0x2C])));
+        assert_eq!(Cid::new([0x0E, 0x1B, 0x2C]), Err(Error::InvalidPrefix));
+        // An OUI, with the U/L bit clear, is not a CID.
+        assert_eq!(Cid::new([0x08, 0x1B, 0x2C]), Err(Error::InvalidPrefix));
+        let cid: Cid = "1a:1b:2c".parse().unwrap();
+        assert_eq!(cid.octets(), [0x1A, 0x1B, 0x2C]);
+        assert_eq!(cid.to_string(), "1A-1B-2C");
+        assert_eq!("0E-1B-2C".parse::<Cid>(), Err(Error::InvalidPrefix));
+    }
+
+    #[test]
+    fn cid_of_other_quadrants() {
+        assert_eq!(Eui([0x0E, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]).cid(), None);
+        assert_eq!(Eui([0x08, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]).cid(), None);
+    }
+
+    #[test]
+    fn cid_of_group_address() {
+        let cid = Eui([0x1B, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]).cid().unwrap();
+        assert_eq!(cid.octets(), [0x1A, 0x1B, 0x2C]);
+        assert_eq!(Cid::new(cid.octets()), Ok(cid));
+    }
+}
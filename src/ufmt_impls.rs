@@ -0,0 +1,64 @@
+//! Formats addresses through `ufmt`, for targets where `core::fmt` is too
+//! heavy.
+
+use crate::{Eui, EuiAddr, Width, EUI};
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Writes the address canonically, like `Display`.
+impl<const N: usize> uDisplay for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.format_into(&mut [0; 32]))
+    }
+}
+
+/// Writes the address canonically, like `uDisplay`.
+impl<const N: usize> uDebug for Eui<N>
+where
+    [u8; N]: Width,
+{
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(self, f)
+    }
+}
+
+impl uDisplay for EuiAddr {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.format_into(&mut [0; 32]))
+    }
+}
+
+impl uDebug for EuiAddr {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui, EuiAddr};
+    use core::convert::Infallible;
+    use ufmt::{uWrite, uwrite};
+
+    struct Buf(String);
+
+    impl uWrite for Buf {
+        type Error = Infallible;
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn display() {
+        let mut buf = Buf(String::new());
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        uwrite!(buf, "mac {} ", eui).unwrap();
+        let eui = Eui([0x00, 0xFF, 0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        uwrite!(buf, "{:?}", EuiAddr::EUI64(eui)).unwrap();
+        assert_eq!(buf.0, "mac 0A-1B-2C-3D-4E-5F 00-FF-0A-1B-2C-3D-4E-5F");
+    }
+}
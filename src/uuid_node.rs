@@ -0,0 +1,46 @@
+//! RFC 4122 version-1 UUID node fields derived from an EUI-48.
+
+use crate::EUI48;
+use uuid::Uuid;
+
+impl EUI48 {
+    /// Returns the address as a UUID node field with the multicast bit set,
+    /// which RFC 4122 (section 4.5) uses to mark node IDs that are not taken
+    /// verbatim from a network card, so they can't collide with real ones.
+    pub fn to_uuid_node(&self) -> [u8; 6] {
+        self.with_multicast_bit(true).0
+    }
+
+    /// Returns whether `uuid`'s node field was derived from this address,
+    /// either verbatim or through [`EUI48::to_uuid_node`].
+    pub fn matches_uuid_node(&self, uuid: &Uuid) -> bool {
+        let node = &uuid.as_bytes()[10..];
+        node[0] & !0x01 == self.0[0] & !0x01 && node[1..] == self.0[1..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Eui;
+    use uuid::Uuid;
+
+    #[test]
+    fn to_uuid_node() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let node = eui.to_uuid_node();
+        assert_eq!(node, [0x0B, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert_eq!(node[0] & 0x01, 0x01);
+    }
+
+    #[test]
+    fn matches_uuid_node() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let mut bytes = [0x11; 16];
+        bytes[10..].copy_from_slice(&eui.to_uuid_node());
+        assert!(eui.matches_uuid_node(&Uuid::from_bytes(bytes)));
+        bytes[10..].copy_from_slice(&[0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        assert!(eui.matches_uuid_node(&Uuid::from_bytes(bytes)));
+        bytes[15] = 0x60;
+        assert!(!eui.matches_uuid_node(&Uuid::from_bytes(bytes)));
+    }
+}
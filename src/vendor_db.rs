@@ -0,0 +1,156 @@
+//! Vendor databases loaded at runtime from Wireshark `manuf` files.
+
+use crate::{parser, Error, Eui, EuiPrefix, PrefixMap, EUI48};
+use alloc::string::{String, ToString};
+
+/// Maps address prefixes to vendor names, resolving each address against the
+/// longest matching prefix like Wireshark does.
+#[derive(Clone, Debug, Default)]
+pub struct VendorDb {
+    entries: PrefixMap<String>,
+}
+
+impl VendorDb {
+    pub fn new() -> Self {
+        VendorDb::default()
+    }
+
+    /// Parses a `manuf` file: one `<prefix>[/<bits>]\t<short name>[\t<name>]`
+    /// entry per line, with `#` starting a comment line. Prefixes without a
+    /// length cover all the octets given. Later entries override earlier ones
+    /// for the same prefix.
+    pub fn parse(s: &str) -> Result<VendorDb, Error> {
+        let mut db = VendorDb::new();
+        db.extend_from_manuf(s)?;
+        Ok(db)
+    }
+
+    /// Adds the entries of a `manuf` file, e.g. local overrides on top of a
+    /// stock database.
+    pub fn extend_from_manuf(&mut self, s: &str) -> Result<(), Error> {
+        for line in s.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split('\t').map(str::trim).filter(|f| !f.is_empty());
+            let prefix = fields.next().ok_or(Error::InvalidPrefix)?;
+            let short_name = fields.next().ok_or(Error::InvalidPrefix)?;
+            let name = fields.next().unwrap_or(short_name);
+
+            let (base, bits) = parse_prefix(prefix)?;
+            self.insert(base, bits, name)?;
+        }
+        Ok(())
+    }
+
+    /// Registers `name` for the first `bits` bits of `base`.
+    pub fn insert(&mut self, base: EUI48, bits: u8, name: &str) -> Result<(), Error> {
+        self.entries
+            .insert(EuiPrefix::new(base, bits)?, name.to_string());
+        Ok(())
+    }
+
+    /// Returns the vendor of the longest prefix that covers `eui`.
+    pub fn lookup(&self, eui: &EUI48) -> Option<&str> {
+        self.entries.lookup(eui).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl core::str::FromStr for VendorDb {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        VendorDb::parse(s)
+    }
+}
+
+fn parse_prefix(s: &str) -> Result<(EUI48, u8), Error> {
+    let (address, bits) = match s.split_once('/') {
+        Some((address, bits)) => (
+            address,
+            Some(bits.parse().map_err(|_| Error::InvalidPrefix)?),
+        ),
+        None => (s, None),
+    };
+    let digits = address.bytes().filter(u8::is_ascii_hexdigit).count();
+    if digits == 6 {
+        let oui: [u8; 3] = parser::decode(address, true)?;
+        Ok((Eui([oui[0], oui[1], oui[2], 0, 0, 0]), bits.unwrap_or(24)))
+    } else {
+        Ok((Eui(parser::decode(address, true)?), bits.unwrap_or(48)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VendorDb;
+    use crate::{Error, Eui};
+
+    const MANUF: &str = "\
+# Sample of the Wireshark manuf format.
+00:00:0C\tCisco\tCisco Systems, Inc
+00:50:C2\tIeeeRegi\tIEEE Registration Authority
+00:50:C2:00:30:00/36\tLockheed\tLockheed Martin Orincon Corp
+70:B3:D5:10:00:00/28\tExample\tExample Networks
+
+08:00:27\tPcsCompu
+";
+
+    #[test]
+    fn parse() {
+        let db: VendorDb = MANUF.parse().unwrap();
+        assert_eq!(db.len(), 5);
+        let eui = Eui([0x00, 0x00, 0x0C, 0x12, 0x34, 0x56]);
+        assert_eq!(db.lookup(&eui), Some("Cisco Systems, Inc"));
+        let eui = Eui([0x08, 0x00, 0x27, 0x12, 0x34, 0x56]);
+        assert_eq!(db.lookup(&eui), Some("PcsCompu"));
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let db = VendorDb::parse(MANUF).unwrap();
+        let eui = Eui([0x00, 0x50, 0xC2, 0x00, 0x3A, 0xBC]);
+        assert_eq!(db.lookup(&eui), Some("Lockheed Martin Orincon Corp"));
+        let eui = Eui([0x00, 0x50, 0xC2, 0x00, 0x4A, 0xBC]);
+        assert_eq!(db.lookup(&eui), Some("IEEE Registration Authority"));
+        let eui = Eui([0x70, 0xB3, 0xD5, 0x1F, 0xFF, 0xFF]);
+        assert_eq!(db.lookup(&eui), Some("Example Networks"));
+        let eui = Eui([0x70, 0xB3, 0xD5, 0x20, 0x00, 0x00]);
+        assert_eq!(db.lookup(&eui), None);
+    }
+
+    #[test]
+    fn overrides() {
+        let mut db = VendorDb::parse(MANUF).unwrap();
+        db.extend_from_manuf("00:00:0C\tLab\tLab switches\n")
+            .unwrap();
+        let eui = Eui([0x00, 0x00, 0x0C, 0x12, 0x34, 0x56]);
+        assert_eq!(db.lookup(&eui), Some("Lab switches"));
+        assert_eq!(db.len(), 5);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            VendorDb::parse("00:00:0C\n").unwrap_err(),
+            Error::InvalidPrefix
+        );
+        assert_eq!(
+            VendorDb::parse("00:50:C2:00:30:00/49\tX\n").unwrap_err(),
+            Error::InvalidPrefix
+        );
+        assert_eq!(
+            VendorDb::parse("00:00:0G\tX\n").unwrap_err(),
+            Error::InvalidHexCharacter { c: 'G', index: 7 }
+        );
+    }
+}
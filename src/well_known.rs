@@ -0,0 +1,88 @@
+//! Recognition of reserved and protocol-specific addresses.
+
+use crate::EUI48;
+
+/// A reserved or protocol address that capture tools commonly label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WellKnown {
+    /// `FF-FF-FF-FF-FF-FF`.
+    Broadcast,
+    /// Spanning Tree Protocol, `01-80-C2-00-00-00`.
+    Stp,
+    /// Link Layer Discovery Protocol, `01-80-C2-00-00-0E`.
+    Lldp,
+    /// The other IEEE 802.1 reserved group addresses, `01-80-C2-00-00-01` to
+    /// `01-80-C2-00-00-0F`.
+    BridgeGroup,
+    /// IPv4 multicast, `01-00-5E-00-00-00` to `01-00-5E-7F-FF-FF`.
+    Ipv4Multicast,
+    /// IPv6 multicast, `33-33-xx-xx-xx-xx`.
+    Ipv6Multicast,
+    /// VRRP virtual router for IPv4, `00-00-5E-00-01-xx`.
+    Vrrp,
+    /// VRRP virtual router for IPv6, `00-00-5E-00-02-xx`.
+    VrrpIpv6,
+    /// Other addresses under the IANA OUI `00-00-5E`.
+    Iana,
+    /// Cisco Discovery Protocol (also VTP, DTP and UDLD), `01-00-0C-CC-CC-CC`.
+    Cdp,
+    /// Cisco Per-VLAN Spanning Tree Plus, `01-00-0C-CC-CC-CD`.
+    Pvst,
+}
+
+impl EUI48 {
+    /// Recognizes reserved and protocol addresses, returning `None` for
+    /// anything else.
+    pub fn classify(&self) -> Option<WellKnown> {
+        let b = self.0;
+        Some(match b {
+            _ if self.is_broadcast() => WellKnown::Broadcast,
+            [0x01, 0x80, 0xC2, 0x00, 0x00, 0x00] => WellKnown::Stp,
+            [0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E] => WellKnown::Lldp,
+            _ if self.is_reserved_group() => WellKnown::BridgeGroup,
+            _ if self.as_ipv4_multicast().is_some() => WellKnown::Ipv4Multicast,
+            [0x33, 0x33, ..] => WellKnown::Ipv6Multicast,
+            [0x00, 0x00, 0x5E, 0x00, 0x01, _] => WellKnown::Vrrp,
+            [0x00, 0x00, 0x5E, 0x00, 0x02, _] => WellKnown::VrrpIpv6,
+            [0x00, 0x00, 0x5E, ..] => WellKnown::Iana,
+            [0x01, 0x00, 0x0C, 0xCC, 0xCC, 0xCC] => WellKnown::Cdp,
+            [0x01, 0x00, 0x0C, 0xCC, 0xCC, 0xCD] => WellKnown::Pvst,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{WellKnown, EUI48};
+
+    #[test]
+    fn classify() {
+        let classify = |s: &str| s.parse::<EUI48>().unwrap().classify();
+        assert_eq!(classify("FF-FF-FF-FF-FF-FF"), Some(WellKnown::Broadcast));
+        assert_eq!(classify("01-80-C2-00-00-00"), Some(WellKnown::Stp));
+        assert_eq!(classify("01-80-C2-00-00-0E"), Some(WellKnown::Lldp));
+        assert_eq!(classify("01-80-C2-00-00-02"), Some(WellKnown::BridgeGroup));
+        assert_eq!(
+            classify("01-00-5E-00-00-FB"),
+            Some(WellKnown::Ipv4Multicast)
+        );
+        assert_eq!(
+            classify("33-33-00-00-00-01"),
+            Some(WellKnown::Ipv6Multicast)
+        );
+        assert_eq!(classify("00-00-5E-00-01-0A"), Some(WellKnown::Vrrp));
+        assert_eq!(classify("00-00-5E-00-02-0A"), Some(WellKnown::VrrpIpv6));
+        assert_eq!(classify("00-00-5E-00-53-00"), Some(WellKnown::Iana));
+        assert_eq!(classify("01-00-0C-CC-CC-CC"), Some(WellKnown::Cdp));
+        assert_eq!(classify("01-00-0C-CC-CC-CD"), Some(WellKnown::Pvst));
+    }
+
+    #[test]
+    fn classify_other() {
+        let classify = |s: &str| s.parse::<EUI48>().unwrap().classify();
+        assert_eq!(classify("0A-1B-2C-3D-4E-5F"), None);
+        assert_eq!(classify("01-80-C2-00-00-10"), None);
+        assert_eq!(classify("01-00-5E-80-00-01"), None);
+    }
+}
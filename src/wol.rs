@@ -0,0 +1,54 @@
+//! Wake-on-LAN magic packets.
+
+use crate::EUI48;
+
+/// The synchronization stream followed by 16 repetitions of the address.
+const MAGIC_PACKET_LEN: usize = 6 + 16 * 6;
+
+impl EUI48 {
+    /// Returns the Wake-on-LAN magic packet that wakes the interface with this
+    /// address: six `0xFF` octets followed by 16 repetitions of the address.
+    /// It is usually sent as the payload of a UDP broadcast to port 9.
+    pub fn wol_magic_packet(&self) -> [u8; MAGIC_PACKET_LEN] {
+        let mut packet = [0xFF; MAGIC_PACKET_LEN];
+        for chunk in packet[6..].chunks_exact_mut(6) {
+            chunk.copy_from_slice(&self.0);
+        }
+        packet
+    }
+
+    /// Returns the magic packet for an interface with a SecureOn password,
+    /// which follows the repetitions of the address.
+    pub fn wol_magic_packet_secure_on(&self, password: [u8; 6]) -> [u8; MAGIC_PACKET_LEN + 6] {
+        let mut packet = [0u8; MAGIC_PACKET_LEN + 6];
+        packet[..MAGIC_PACKET_LEN].copy_from_slice(&self.wol_magic_packet());
+        packet[MAGIC_PACKET_LEN..].copy_from_slice(&password);
+        packet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Eui;
+
+    #[test]
+    fn magic_packet() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let packet = eui.wol_magic_packet();
+        assert_eq!(packet.len(), 102);
+        assert_eq!(packet[..6], [0xFF; 6]);
+        for chunk in packet[6..].chunks(6) {
+            assert_eq!(chunk, eui.octets());
+        }
+    }
+
+    #[test]
+    fn magic_packet_secure_on() {
+        let eui = Eui([0x0A, 0x1B, 0x2C, 0x3D, 0x4E, 0x5F]);
+        let password = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let packet = eui.wol_magic_packet_secure_on(password);
+        assert_eq!(packet.len(), 108);
+        assert_eq!(packet[..102], eui.wol_magic_packet());
+        assert_eq!(packet[102..], password);
+    }
+}
@@ -0,0 +1,213 @@
+//! Fibre Channel World Wide Names and InfiniBand GUIDs.
+
+use crate::{Case, Error, Eui, Format, Oui, ParseOptions, EUI, EUI48, EUI64};
+
+/// The Network Address Authority of a 64-bit World Wide Name, from its top
+/// nibble.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Naa {
+    /// `1`: `10:00` followed by an EUI-48.
+    Ieee,
+    /// `2`: 12 vendor bits followed by an EUI-48.
+    IeeeExtended,
+    /// `3`: locally assigned.
+    Local,
+    /// `5`: an OUI followed by 36 vendor bits.
+    IeeeRegistered,
+}
+
+impl Naa {
+    fn from_nibble(nibble: u8) -> Option<Naa> {
+        match nibble {
+            1 => Some(Naa::Ieee),
+            2 => Some(Naa::IeeeExtended),
+            3 => Some(Naa::Local),
+            5 => Some(Naa::IeeeRegistered),
+            _ => None,
+        }
+    }
+}
+
+/// A 64-bit Fibre Channel World Wide Name, with a valid NAA.
+///
+/// `Display` uses the lowercase colon form of storage tooling
+/// (`50:06:01:60:3b:20:19:b8`), and `FromStr` also accepts the `0x` form of
+/// Linux sysfs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Wwn(EUI64);
+
+impl Wwn {
+    /// Wraps `eui`, failing with `InvalidPrefix` if its NAA is not one of
+    /// the 64-bit ones, or if it is NAA 1 and the 12 reserved bits after the
+    /// NAA are not zero.
+    pub fn new(eui: EUI64) -> Result<Wwn, Error> {
+        match Naa::from_nibble(eui.0[0] >> 4) {
+            Some(Naa::Ieee) if eui.0[0] & 0x0F != 0 || eui.0[1] != 0 => Err(Error::InvalidPrefix),
+            Some(_) => Ok(Wwn(eui)),
+            None => Err(Error::InvalidPrefix),
+        }
+    }
+
+    /// Builds the NAA 1 name of a port with MAC address `eui`.
+    pub fn from_eui48(eui: EUI48) -> Wwn {
+        let o = eui.0;
+        Wwn(Eui([0x10, 0x00, o[0], o[1], o[2], o[3], o[4], o[5]]))
+    }
+
+    pub const fn eui64(&self) -> EUI64 {
+        self.0
+    }
+
+    pub fn naa(&self) -> Naa {
+        // Checked on construction.
+        Naa::from_nibble(self.0 .0[0] >> 4).unwrap()
+    }
+
+    /// Returns the OUI of the organization that assigned the name, or `None`
+    /// if it was assigned locally.
+    pub fn oui(&self) -> Option<Oui> {
+        let o = self.0 .0;
+        match self.naa() {
+            Naa::Ieee | Naa::IeeeExtended => Some(Oui::new([o[2], o[3], o[4]])),
+            Naa::IeeeRegistered => Some(Oui::new([
+                o[0] << 4 | o[1] >> 4,
+                o[1] << 4 | o[2] >> 4,
+                o[2] << 4 | o[3] >> 4,
+            ])),
+            Naa::Local => None,
+        }
+    }
+}
+
+impl core::convert::TryFrom<EUI64> for Wwn {
+    type Error = crate::Error;
+    fn try_from(eui: EUI64) -> Result<Self, Self::Error> {
+        Wwn::new(eui)
+    }
+}
+
+impl From<Wwn> for EUI64 {
+    fn from(wwn: Wwn) -> Self {
+        wwn.0
+    }
+}
+
+impl core::str::FromStr for Wwn {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Wwn::new(EUI64::parse_with(s, ParseOptions::new().hex_prefix(true))?)
+    }
+}
+
+impl core::fmt::Display for Wwn {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.write_format_with(f, Format::Colon, Case::Lower)
+    }
+}
+
+/// An InfiniBand node, port or system image GUID.
+///
+/// `Display` uses the `0x0002c903000e0b72` form of `ibstat`, and `FromStr`
+/// accepts it as well as the usual EUI-64 forms.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Guid(EUI64);
+
+impl Guid {
+    pub const fn new(eui: EUI64) -> Guid {
+        Guid(eui)
+    }
+
+    pub const fn eui64(&self) -> EUI64 {
+        self.0
+    }
+}
+
+impl From<EUI64> for Guid {
+    fn from(eui: EUI64) -> Self {
+        Guid(eui)
+    }
+}
+
+impl From<Guid> for EUI64 {
+    fn from(guid: Guid) -> Self {
+        guid.0
+    }
+}
+
+impl core::str::FromStr for Guid {
+    type Err = crate::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EUI64::parse_with(s, ParseOptions::new().hex_prefix(true)).map(Guid)
+    }
+}
+
+impl core::fmt::Display for Guid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("0x")?;
+        self.0.write_format_with(f, Format::Bare, Case::Lower)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Guid, Naa, Wwn};
+    use crate::{Error, Eui, Oui};
+
+    #[test]
+    fn naa() {
+        let wwn = |octet| Wwn::new(Eui([octet, 0x06, 0x01, 0x60, 0x3B, 0x20, 0x19, 0xB8]));
+        assert_eq!(wwn(0x2F).unwrap().naa(), Naa::IeeeExtended);
+        assert_eq!(wwn(0x30).unwrap().naa(), Naa::Local);
+        assert_eq!(wwn(0x50).unwrap().naa(), Naa::IeeeRegistered);
+        assert_eq!(wwn(0x60), Err(Error::InvalidPrefix));
+        assert_eq!(wwn(0x00), Err(Error::InvalidPrefix));
+    }
+
+    #[test]
+    fn naa_ieee_reserved_bits() {
+        let wwn = |octets: [u8; 2]| {
+            Wwn::new(Eui([
+                octets[0], octets[1], 0x00, 0x05, 0x1E, 0x3D, 0x4E, 0x5F,
+            ]))
+        };
+        assert_eq!(wwn([0x10, 0x00]).unwrap().naa(), Naa::Ieee);
+        assert_eq!(wwn([0x10, 0x06]), Err(Error::InvalidPrefix));
+        assert_eq!(wwn([0x11, 0x00]), Err(Error::InvalidPrefix));
+        assert_eq!(
+            "10:01:00:05:1e:3d:4e:5f".parse::<Wwn>(),
+            Err(Error::InvalidPrefix)
+        );
+    }
+
+    #[test]
+    fn oui() {
+        let wwn: Wwn = "50:06:01:60:3b:20:19:b8".parse().unwrap();
+        assert_eq!(wwn.oui(), Some(Oui::new([0x00, 0x60, 0x16])));
+        let wwn = Wwn::from_eui48(Eui([0x00, 0x05, 0x1E, 0x3D, 0x4E, 0x5F]));
+        assert_eq!(wwn.naa(), Naa::Ieee);
+        assert_eq!(wwn.oui(), Some(Oui::new([0x00, 0x05, 0x1E])));
+        let wwn: Wwn = "30:00:00:00:00:00:00:01".parse().unwrap();
+        assert_eq!(wwn.oui(), None);
+    }
+
+    #[test]
+    fn wwn_formats() {
+        let wwn: Wwn = "0x500601603b2019b8".parse().unwrap();
+        assert_eq!(wwn.to_string(), "50:06:01:60:3b:20:19:b8");
+        assert_eq!(
+            "0x600601603b2019b8".parse::<Wwn>(),
+            Err(Error::InvalidPrefix)
+        );
+    }
+
+    #[test]
+    fn guid() {
+        let guid: Guid = "0x0002c903000e0b72".parse().unwrap();
+        assert_eq!(
+            guid.eui64(),
+            Eui([0x00, 0x02, 0xC9, 0x03, 0x00, 0x0E, 0x0B, 0x72])
+        );
+        assert_eq!(guid.to_string(), "0x0002c903000e0b72");
+        assert_eq!("0002:c903:000e:0b72".parse(), Ok(guid));
+    }
+}